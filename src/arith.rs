@@ -1,3 +1,3 @@
 pub fn modulo(n: i32, m: i32) -> i32 {
-    i32::rem_euclid(n as i32, m as i32) as i32
+    i32::rem_euclid(n, m)
 }
@@ -0,0 +1,150 @@
+//! Timestamped snapshots of the files in a working directory, kept in `.rc500/backups/<id>/`.
+//!
+//! A snapshot is a copy of the memory and system files as they are, plus the reason it was taken. Taking a
+//! snapshot of files that are the same as in the latest snapshot does nothing, so snapshots can be taken
+//! freely before and after every operation.
+//!
+//! Before a push, the files on the device are archived in `.rc500/device-backups/<id>/` in the same way.
+
+use crate::diff;
+use crate::error::Rc500Error;
+use crate::io;
+use crate::reader;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const REASON_FILE: &str = "REASON";
+
+pub struct Snapshot {
+    /// The time the snapshot was taken, like 20240131-235959
+    pub id: String,
+    pub reason: String,
+    pub dir: PathBuf,
+}
+
+pub fn backups_dir(working_dir: &str) -> PathBuf {
+    Path::new(working_dir).join(".rc500").join("backups")
+}
+
+pub fn device_backups_dir(working_dir: &str) -> PathBuf {
+    Path::new(working_dir).join(".rc500").join("device-backups")
+}
+
+fn files() -> impl Iterator<Item = &'static str> {
+    io::MEMORY_FILES.into_iter().chain(io::SYSTEM_FILES)
+}
+
+/// The content of the files that exist in the directory.
+fn contents(dir: &Path) -> Vec<(&'static str, Vec<u8>)> {
+    files()
+        .filter_map(|name| fs::read(dir.join(name)).ok().map(|content| (name, content)))
+        .collect()
+}
+
+/// Take a snapshot of the files in the working directory. Gives the id of the snapshot, or None if there
+/// are no files or they did not change since the latest snapshot.
+pub fn create(working_dir: &str, reason: &str) -> Result<Option<String>, Rc500Error> {
+    let contents = contents(Path::new(working_dir));
+    if contents.is_empty() {
+        return Ok(None);
+    }
+    if let Some(latest) = list(working_dir)?.last() {
+        if self::contents(&latest.dir) == contents {
+            return Ok(None);
+        }
+    }
+    write(&backups_dir(working_dir), &contents, reason).map(Some)
+}
+
+/// Keep a copy of the files read from the device before they are replaced. Gives the directory they are in.
+pub fn archive_device(working_dir: &str, contents: &[(&str, Vec<u8>)]) -> Result<PathBuf, Rc500Error> {
+    let base = device_backups_dir(working_dir);
+    write(&base, contents, "push").map(|id| base.join(id))
+}
+
+/// Write the files to a new snapshot directory in `base` and give its id.
+fn write(base: &Path, contents: &[(&str, Vec<u8>)], reason: &str) -> Result<String, Rc500Error> {
+    let timestamp = chrono::Local::now().format("%Y%m%d-%H%M%S").to_string();
+    let mut id = timestamp.clone();
+    let mut n = 1;
+    while base.join(&id).exists() {
+        n += 1;
+        id = format!("{}-{}", timestamp, n);
+    }
+    let dir = base.join(&id);
+    let io_error = |e: std::io::Error| Rc500Error::io(format!("Could not write the snapshot {:?}: {}", dir, e));
+    fs::create_dir_all(&dir).map_err(io_error)?;
+    for (name, content) in contents {
+        fs::write(dir.join(name), content).map_err(io_error)?;
+    }
+    fs::write(dir.join(REASON_FILE), reason).map_err(io_error)?;
+    Ok(id)
+}
+
+/// All snapshots, oldest first.
+pub fn list(working_dir: &str) -> Result<Vec<Snapshot>, Rc500Error> {
+    let dir = backups_dir(working_dir);
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let entries = fs::read_dir(&dir).map_err(|e| Rc500Error::io(format!("Could not read {:?}: {}", dir, e)))?;
+    let mut snapshots: Vec<Snapshot> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir())
+        .map(|entry| Snapshot {
+            id: entry.file_name().to_string_lossy().to_string(),
+            reason: fs::read_to_string(entry.path().join(REASON_FILE)).unwrap_or_default(),
+            dir: entry.path(),
+        })
+        .collect();
+    snapshots.sort_by_key(|snapshot| order(&snapshot.id));
+    Ok(snapshots)
+}
+
+/// Ids sort by time, and a snapshot taken within the same second as another gets a suffix like -2.
+fn order(id: &str) -> (String, usize) {
+    match id.split_once('-').and_then(|(_, time)| time.split_once('-')) {
+        Some((_, n)) => (id[..id.len() - n.len() - 1].to_string(), n.parse().unwrap_or(0)),
+        None => (id.to_string(), 1),
+    }
+}
+
+/// Take a snapshot before and after running the operation, like a save or pull.
+pub fn around<T, E: From<Rc500Error>>(
+    working_dir: &str,
+    reason: &str,
+    operation: impl FnOnce() -> Result<T, E>,
+) -> Result<T, E> {
+    create(working_dir, &format!("before {}", reason))?;
+    let result = operation()?;
+    create(working_dir, reason)?;
+    Ok(result)
+}
+
+/// The settings that changed in the snapshot compared to the one before it.
+pub fn changes(previous: Option<&Snapshot>, snapshot: &Snapshot) -> Result<Vec<diff::Change>, Rc500Error> {
+    let load = |snapshot: &Snapshot| crate::load(&snapshot.dir.to_string_lossy());
+    let new = load(snapshot)?;
+    let old = match previous {
+        Some(previous) => load(previous)?,
+        None => return Ok(Vec::new()),
+    };
+    Ok(diff::diff(&old, &new))
+}
+
+/// Put the files of the snapshot back in the working directory. The current files are saved in a snapshot
+/// first, so restoring can be undone.
+pub fn restore(working_dir: &str, id: &str) -> Result<(), Rc500Error> {
+    let dir = backups_dir(working_dir).join(id);
+    if !dir.is_dir() {
+        return Err(Rc500Error::invalid_value(format!("There is no snapshot {}", id)));
+    }
+    // Check that the snapshot can be read before replacing anything
+    reader::read(&dir.join(io::MEMORY_FILES[0]))?;
+    create(working_dir, &format!("before restoring {}", id))?;
+    for (name, content) in contents(&dir) {
+        let path = Path::new(working_dir).join(name);
+        fs::write(&path, content).map_err(|e| Rc500Error::io(format!("Could not write {:?}: {}", path, e)))?;
+    }
+    Ok(())
+}
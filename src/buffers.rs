@@ -0,0 +1,171 @@
+//! The two-slot scheme the RC500 uses for its files.
+//!
+//! Memories and system settings are each stored twice: slot A (`MEMORY1.RC0`, `SYSTEM1.RC0`) and slot B
+//! (`MEMORY2.RC0`, `SYSTEM2.RC0`). The device uses the slot with the newest generation in its trailer, so a
+//! save that is interrupted halfway leaves the other slot intact. Saving writes the new content into the
+//! inactive slot with the next generation, which makes it the active one, and then copies the same content
+//! into the other slot, keeping its generation. Both slots then hold the same settings.
+
+use crate::error::Rc500Error;
+use crate::reader;
+use crate::trailer::Trailer;
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Slot {
+    A,
+    B,
+}
+
+impl Slot {
+    pub fn index(self) -> usize {
+        match self {
+            Slot::A => 0,
+            Slot::B => 1,
+        }
+    }
+
+    pub fn other(self) -> Slot {
+        match self {
+            Slot::A => Slot::B,
+            Slot::B => Slot::A,
+        }
+    }
+}
+
+/// The slot the device uses. On a tie the device, like us, takes slot A.
+pub fn active_slot(a: &Trailer, b: &Trailer) -> Slot {
+    if b.is_newer_than(a) {
+        Slot::B
+    } else {
+        Slot::A
+    }
+}
+
+/// The state of both slots of a file pair on disk.
+pub struct Status {
+    pub paths: [PathBuf; 2],
+    /// The trailer of each slot, or why it could not be read.
+    pub trailers: [Result<Trailer, String>; 2],
+    /// Whether both slots contain the same XML.
+    pub consistent: bool,
+}
+
+impl Status {
+    /// The slot the device will use, if any slot can be read.
+    pub fn active(&self) -> Option<Slot> {
+        match &self.trailers {
+            [Ok(a), Ok(b)] => Some(active_slot(a, b)),
+            [Ok(_), Err(_)] => Some(Slot::A),
+            [Err(_), Ok(_)] => Some(Slot::B),
+            [Err(_), Err(_)] => None,
+        }
+    }
+
+    /// Why the active slot is the active one, in words.
+    pub fn reason(&self) -> String {
+        let name = |slot: Slot| file_name(&self.paths[slot.index()]);
+        match (&self.trailers, self.active()) {
+            ([Ok(a), Ok(b)], Some(slot)) if a.generation == b.generation => format!(
+                "{} is active because both slots have generation {} and the first one wins a tie",
+                name(slot),
+                a.generation
+            ),
+            ([Ok(a), Ok(b)], Some(slot)) => {
+                let (active, other) = if slot == Slot::A { (a, b) } else { (b, a) };
+                format!(
+                    "{} is active because its generation {} is newer than {}",
+                    name(slot),
+                    active.generation,
+                    other.generation
+                )
+            }
+            (_, Some(slot)) => format!("{} is active because {} cannot be read", name(slot), name(slot.other())),
+            (_, None) => "Neither slot can be read".to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for (path, trailer) in self.paths.iter().zip(&self.trailers) {
+            match trailer {
+                Ok(trailer) => writeln!(f, "{}: generation {}", file_name(path), trailer.generation)?,
+                Err(e) => writeln!(f, "{}: {}", file_name(path), e)?,
+            }
+        }
+        writeln!(f, "{}", self.reason())?;
+        if self.consistent {
+            write!(f, "Both slots contain the same settings")
+        } else {
+            write!(f, "The slots contain different settings")
+        }
+    }
+}
+
+pub fn status(dir: &Path, names: [&str; 2]) -> Status {
+    let paths = [dir.join(names[0]), dir.join(names[1])];
+    let contents = [reader::read_parts(&paths[0]), reader::read_parts(&paths[1])];
+    let consistent = match &contents {
+        [Ok((xml_a, _)), Ok((xml_b, _))] => xml_a == xml_b,
+        _ => false,
+    };
+    let [a, b] = contents;
+    Status {
+        paths,
+        trailers: [a, b].map(|content| content.map(|(_, trailer)| trailer).map_err(|e| e.message().to_string())),
+        consistent,
+    }
+}
+
+/// Save the content rendered by `render` for a given trailer. If `filename` is one of the slots of a pair,
+/// both slots are written as described in the module documentation. Otherwise only that file is written.
+/// Returns the active file and its trailer.
+pub fn save(
+    filename: &str,
+    names: [&str; 2],
+    trailer: &Trailer,
+    render: impl Fn(&Trailer) -> Vec<u8>,
+) -> Result<(String, Trailer), Rc500Error> {
+    let path = Path::new(filename);
+    let is_slot = names
+        .iter()
+        .any(|name| path.file_name().is_some_and(|file_name| file_name == *name));
+    if !is_slot {
+        let next = trailer.next();
+        write(path, &render(&next))?;
+        return Ok((filename.to_string(), next));
+    }
+    let dir = path.parent().unwrap_or_else(|| Path::new(""));
+    let status = status(dir, names);
+    // Continue from what is on disk, in case the files were changed since they were read
+    let (active, current) = match (status.active(), &status.trailers) {
+        (Some(slot), trailers) => match &trailers[slot.index()] {
+            Ok(current) => (slot, current.generation),
+            Err(_) => (slot, trailer.generation),
+        },
+        (None, _) => (Slot::B, trailer.generation),
+    };
+    let next = Trailer {
+        generation: current.wrapping_add(1),
+        rest: trailer.rest.clone(),
+    };
+    let previous = Trailer {
+        generation: current,
+        rest: trailer.rest.clone(),
+    };
+    let target = &status.paths[active.other().index()];
+    write(target, &render(&next))?;
+    write(&status.paths[active.index()], &render(&previous))?;
+    Ok((target.to_string_lossy().to_string(), next))
+}
+
+fn write(path: &Path, content: &[u8]) -> Result<(), Rc500Error> {
+    std::fs::write(path, content).map_err(|e| Rc500Error::io(format!("Error while writing {:?}: {}", path, e)))
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map_or_else(|| path.to_string_lossy(), |name| name.to_string_lossy())
+        .to_string()
+}
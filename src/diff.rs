@@ -0,0 +1,133 @@
+//! The differences between two configs, setting by setting.
+
+use crate::model;
+use crate::schema;
+use serde::Serialize;
+use std::fmt;
+
+/// A setting that differs between two configs. A value is None if the setting is missing from that config.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Change {
+    /// The memory number as shown on the device, counting from 1
+    pub memory: usize,
+    /// The name of the memory in the second config, or in the first if the second does not have it
+    pub memory_name: String,
+    pub menu: String,
+    pub key: String,
+    /// The display name of the setting
+    pub name: String,
+    pub old: Option<String>,
+    pub new: Option<String>,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let show = |value: &Option<String>| value.clone().unwrap_or_else(|| "(missing)".to_string());
+        write!(
+            f,
+            "Memory {} ({}) / {} / {}: {} -> {}",
+            self.memory,
+            self.memory_name,
+            self.menu,
+            self.name,
+            show(&self.old),
+            show(&self.new)
+        )
+    }
+}
+
+/// The settings that differ, ordered by memory, menu and key as in the configs.
+pub fn diff(a: &model::Config, b: &model::Config) -> Vec<Change> {
+    let mut changes = Vec::new();
+    let mut ids: Vec<usize> = a.memories.iter().map(|m| m.id).collect();
+    for memory in &b.memories {
+        if !ids.contains(&memory.id) {
+            ids.push(memory.id);
+        }
+    }
+    for id in ids {
+        let old = a.memories.iter().find(|m| m.id == id);
+        let new = b.memories.iter().find(|m| m.id == id);
+        let memory_name = new.or(old).map(model::get_memory_name).unwrap_or_default();
+        for (old, new) in diff_memory(old, new) {
+            let Some(setting) = old.as_ref().or(new.as_ref()) else {
+                continue;
+            };
+            changes.push(Change {
+                memory: id + 1,
+                memory_name: memory_name.to_string(),
+                menu: setting.menu.clone(),
+                key: setting.key.clone(),
+                name: setting.name.clone(),
+                old: old.map(|setting| setting.display),
+                new: new.map(|setting| setting.display),
+            });
+        }
+    }
+    changes
+}
+
+/// A single setting of a memory. The NAME is a setting too, with key NAME.
+#[derive(Clone)]
+struct Setting {
+    menu: String,
+    key: String,
+    name: String,
+    /// The value as stored in the file, to compare
+    raw: String,
+    display: String,
+}
+
+fn settings(memory: Option<&model::Memory>) -> Vec<Setting> {
+    let mut result = Vec::new();
+    for menu in memory.iter().flat_map(|memory| &memory.menus) {
+        match &menu.content {
+            model::MenuContent::StringValueMenu(name) => result.push(Setting {
+                menu: menu.name.clone(),
+                key: menu.name.clone(),
+                name: "Name".to_string(),
+                raw: name.value.clone(),
+                display: name.value.clone(),
+            }),
+            model::MenuContent::KeyValueMenu(settings) => {
+                for setting in &settings.settings {
+                    let param = schema::param(&menu.name, &setting.key);
+                    result.push(Setting {
+                        menu: menu.name.clone(),
+                        key: setting.key.clone(),
+                        name: param.map_or_else(|| setting.key.clone(), |param| param.name.to_string()),
+                        raw: setting.value.to_string(),
+                        display: param
+                            .map_or_else(|| setting.value.to_string(), |param| param.display_value(setting.value)),
+                    });
+                }
+            }
+        }
+    }
+    result
+}
+
+/// The old and new version of every setting that differs. A setting is None if it is missing.
+fn diff_memory(old: Option<&model::Memory>, new: Option<&model::Memory>) -> Vec<(Option<Setting>, Option<Setting>)> {
+    let old = settings(old);
+    let new = settings(new);
+    let find = |settings: &[Setting], setting: &Setting| {
+        settings
+            .iter()
+            .find(|s| s.menu == setting.menu && s.key == setting.key)
+            .cloned()
+    };
+    let mut changes = Vec::new();
+    for setting in &old {
+        let new_setting = find(&new, setting);
+        if new_setting.as_ref().map(|s| &s.raw) != Some(&setting.raw) {
+            changes.push((Some(setting.clone()), new_setting));
+        }
+    }
+    for setting in &new {
+        if find(&old, setting).is_none() {
+            changes.push((None, Some(setting.clone())));
+        }
+    }
+    changes
+}
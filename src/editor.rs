@@ -13,28 +13,27 @@ use tui::{
     layout::{Constraint, Direction, Layout},
     style::{Color, Modifier, Style},
     text::{Span, Spans, Text},
-    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Wrap},
     Frame, Terminal,
 };
 
 use crate::arith;
-use crate::descriptions;
-use crate::io;
-use crate::model;
-use crate::writer;
-
-#[derive(Debug, PartialEq, Eq)]
+use boss_rc500::backup;
+use boss_rc500::io;
+use boss_rc500::lint;
+use boss_rc500::model;
+use boss_rc500::schema;
+use boss_rc500::slots;
+use boss_rc500::Rc500Error;
+
+#[derive(Debug, Default, PartialEq, Eq)]
 enum Focus {
+    #[default]
     Memory,
     Menu,
     Setting,
     Edit,
 }
-impl Default for Focus {
-    fn default() -> Focus {
-        Focus::Memory
-    }
-}
 
 #[derive(Default)]
 struct BoundedIndex {
@@ -60,20 +59,39 @@ struct MenuIndex(BoundedIndex);
 #[derive(Default)]
 struct SettingIndex(BoundedIndex);
 
+#[derive(Default)]
 enum Clipboard {
+    #[default]
     Empty,
-    CopiedMemory(model::Memory),
+    /// The id of the memory that was copied
+    CopiedMemory(usize),
 }
 
-impl Default for Clipboard {
-    fn default() -> Clipboard {
-        Clipboard::Empty
-    }
+#[derive(Clone, Copy)]
+enum DeviceAction {
+    Push,
+    Pull,
+}
+
+/// Shown instead of pushing or pulling when several RC500s are connected and none was chosen yet.
+struct DeviceChooser {
+    devices: Vec<io::Device>,
+    action: DeviceAction,
+    index: BoundedIndex,
+    state: ListState,
 }
 
 #[derive(Default)]
 struct UiState {
     working_dir: String,
+    device_root: Option<String>,
+    /// The id of the RC500 to push to and pull from, once chosen
+    device: Option<String>,
+    chooser: Option<DeviceChooser>,
+    /// Take snapshots in .rc500/backups/ when saving, pushing and pulling
+    backup: bool,
+    /// The lint rules that are silenced, by memory
+    lint_allow: lint::Allow,
 
     memory: MemoryIndex,
     menu: MenuIndex,
@@ -81,6 +99,8 @@ struct UiState {
     focus: Focus,
     clipboard: Clipboard,
     message: Option<String>,
+    /// The memory id, and the menu if not the whole memory, that is reset when `d` is pressed again
+    pending_reset: Option<(usize, Option<String>)>,
 
     memory_state: ListState,
     menu_state: ListState,
@@ -95,32 +115,107 @@ fn post_message(ui_state: &mut UiState, msg: &str) {
     ui_state.message = Some(msg.to_string());
 }
 
-fn nr_memories(config: &model::Config) -> usize {
-    config.memories.len()
+/// The memories, followed by the system settings if there are any.
+fn nr_slots(config: &model::Config, system: &Option<model::SystemConfig>) -> usize {
+    config.memories.len() + usize::from(system.is_some())
+}
+
+fn is_system_selected(config: &model::Config, system: &Option<model::SystemConfig>, ui_state: &UiState) -> bool {
+    ui_state.memory.0.get(nr_slots(config, system)) == config.memories.len()
+}
+
+fn nr_menus(config: &model::Config, system: &Option<model::SystemConfig>, ui_state: &UiState) -> usize {
+    get_selected_menus(config, system, ui_state).len()
+}
+
+fn get_selected_memory<'a>(config: &'a model::Config, ui_state: &UiState) -> Option<&'a model::Memory> {
+    config.memories.get(ui_state.memory.0.index)
 }
 
-fn nr_menus(config: &model::Config) -> usize {
-    config.memories[0].menus.len()
+/// The lint findings of the selected memory, if a memory is selected.
+fn get_selected_findings(config: &model::Config, ui_state: &UiState) -> Vec<lint::Finding> {
+    get_selected_memory(config, ui_state)
+        .map(|memory| lint::lint_memory(memory, &ui_state.lint_allow))
+        .unwrap_or_default()
 }
 
-fn get_selected_memory<'a>(config: &'a model::Config, ui_state: &UiState) -> &'a model::Memory {
-    &config.memories[ui_state.memory.0.get(nr_memories(config))]
+fn severity_style(severity: lint::Severity) -> Style {
+    match severity {
+        lint::Severity::Error => Style::default().fg(Color::Red),
+        lint::Severity::Warning => Style::default().fg(Color::Yellow),
+        lint::Severity::Info => Style::default().fg(Color::Cyan),
+    }
 }
 
-fn get_selected_memory_mut<'a>(config: &'a mut model::Config, ui_state: &UiState) -> &'a mut model::Memory {
-    let nr_memories = config.memories.len();
-    &mut config.memories[ui_state.memory.0.get(nr_memories)]
+/// The marker shown next to a setting with lint findings, in the color of the worst one.
+fn lint_marker(findings: &[&lint::Finding]) -> Option<Span<'static>> {
+    let severity = findings.iter().map(|finding| finding.severity).max()?;
+    let rules: Vec<&str> = findings.iter().map(|finding| finding.rule).collect();
+    Some(Span::styled(
+        format!("  ! {}", rules.join(", ")),
+        severity_style(severity),
+    ))
 }
 
-fn get_selected_menu<'a>(config: &'a model::Config, ui_state: &UiState) -> &'a model::Menu {
-    let selected_memory = get_selected_memory(config, ui_state);
-    &selected_memory.menus[ui_state.menu.0.get(nr_menus(config))]
+fn get_selected_menus<'a>(
+    config: &'a model::Config,
+    system: &'a Option<model::SystemConfig>,
+    ui_state: &UiState,
+) -> &'a [model::Menu] {
+    let slot = ui_state.memory.0.get(nr_slots(config, system));
+    match (config.memories.get(slot), system) {
+        (Some(memory), _) => &memory.menus,
+        (None, Some(system)) => &system.menus,
+        (None, None) => &[],
+    }
+}
+
+fn get_selected_menus_mut<'a>(
+    config: &'a mut model::Config,
+    system: &'a mut Option<model::SystemConfig>,
+    ui_state: &UiState,
+) -> &'a mut [model::Menu] {
+    let slot = ui_state.memory.0.get(nr_slots(config, system));
+    match (config.memories.get_mut(slot), system) {
+        (Some(memory), _) => &mut memory.menus,
+        (None, Some(system)) => &mut system.menus,
+        (None, None) => &mut [],
+    }
+}
+
+fn get_selected_menu<'a>(
+    config: &'a model::Config,
+    system: &'a Option<model::SystemConfig>,
+    ui_state: &UiState,
+) -> &'a model::Menu {
+    let menus = get_selected_menus(config, system, ui_state);
+    &menus[ui_state.menu.0.get(menus.len())]
+}
+
+fn get_selected_menu_mut<'a>(
+    config: &'a mut model::Config,
+    system: &'a mut Option<model::SystemConfig>,
+    ui_state: &UiState,
+) -> &'a mut model::Menu {
+    let menus = get_selected_menus_mut(config, system, ui_state);
+    let nr_menus = menus.len();
+    &mut menus[ui_state.menu.0.get(nr_menus)]
+}
+
+/// The parameters of the menu in the schema of the memories. The keys of the system settings are not known
+/// from a real system file, so they are shown and edited as raw values.
+fn menu_params(menu: &model::Menu, is_system: bool) -> Option<&'static schema::Params> {
+    match is_system {
+        true => None,
+        false => schema::menu_params(&menu.name),
+    }
 }
 
-fn get_selected_menu_mut<'a>(config: &'a mut model::Config, ui_state: &UiState) -> &'a mut model::Menu {
-    let selected_memory = get_selected_memory_mut(config, ui_state);
-    let nr_menus = selected_memory.menus.len();
-    &mut selected_memory.menus[ui_state.menu.0.get(nr_menus)]
+fn setting_param(
+    params: Option<&'static schema::Params>,
+    setting: &model::UntypedKeyValue,
+) -> Option<&'static schema::Param> {
+    params.and_then(|params| params.get(setting.key.as_str()))
 }
 
 fn get_selected_setting<'a>(menu: &'a model::UntypedMenu, ui_state: &UiState) -> &'a model::UntypedKeyValue {
@@ -135,11 +230,26 @@ fn get_selected_setting_mut<'a>(
     &mut menu.settings[ui_state.setting.0.get(nr_settings)]
 }
 
-pub fn editor(config: &mut model::Config, working_dir: &str) -> Result<(), String> {
-    init(config, working_dir).map_err(|e| format!("{:?}", e))
+pub fn editor(
+    config: &mut model::Config,
+    system: &mut Option<model::SystemConfig>,
+    working_dir: &str,
+    device_root: Option<&str>,
+    device: Option<&str>,
+    backup: bool,
+) -> Result<(), Rc500Error> {
+    init(config, system, working_dir, device_root, device, backup)
+        .map_err(|e| Rc500Error::io(format!("Could not run the editor in this terminal: {}", e)))
 }
 
-pub fn init(config: &mut model::Config, working_dir: &str) -> Result<(), Box<dyn Error>> {
+pub fn init(
+    config: &mut model::Config,
+    system: &mut Option<model::SystemConfig>,
+    working_dir: &str,
+    device_root: Option<&str>,
+    device: Option<&str>,
+    backup: bool,
+) -> Result<(), Box<dyn Error>> {
     // setup terminal
     enable_raw_mode()?;
     let mut stdout = std::io::stdout();
@@ -147,32 +257,37 @@ pub fn init(config: &mut model::Config, working_dir: &str) -> Result<(), Box<dyn
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    let mut ui_state = UiState::default();
-    ui_state.working_dir = working_dir.to_string();
-    let res = run_app(&mut terminal, config, &mut ui_state);
+    let mut ui_state = UiState {
+        working_dir: working_dir.to_string(),
+        device_root: device_root.map(|root| root.to_string()),
+        device: device.map(|id| id.to_string()),
+        backup,
+        ..Default::default()
+    };
+    match lint::load_allow(working_dir) {
+        Ok(allow) => ui_state.lint_allow = allow,
+        Err(e) => post_message(&mut ui_state, &e.to_string()),
+    }
+    let res = run_app(&mut terminal, config, system, &mut ui_state);
 
     // restore terminal
     disable_raw_mode()?;
     execute!(terminal.backend_mut(), LeaveAlternateScreen, DisableMouseCapture)?;
     terminal.show_cursor()?;
-
-    if let Err(err) = res {
-        println!("{:?}", err)
-    }
-
-    Ok(())
+    res
 }
 
 fn run_app<B: Backend>(
     terminal: &mut Terminal<B>,
     config: &mut model::Config,
+    system: &mut Option<model::SystemConfig>,
     ui_state: &mut UiState,
 ) -> Result<(), Box<dyn Error>> {
     loop {
-        terminal.draw(|f| ui(f, config, ui_state))?;
+        terminal.draw(|f| ui(f, config, system, ui_state))?;
 
         if let Event::Key(key) = event::read()? {
-            match handle_input(config, ui_state, key) {
+            match handle_input(config, system, ui_state, key) {
                 Ok(()) => continue,
                 Err(()) => return Ok(()),
             };
@@ -180,68 +295,185 @@ fn run_app<B: Backend>(
     }
 }
 
-fn save(config: &mut model::Config, ui_state: &mut UiState) -> Result<(), ()> {
+/// Run an operation that changes the local or device files, with snapshots if they are enabled.
+fn backed_up<T>(
+    ui_state: &UiState,
+    reason: &str,
+    operation: impl FnOnce() -> Result<T, Rc500Error>,
+) -> Result<T, Rc500Error> {
+    match ui_state.backup {
+        true => backup::around(&ui_state.working_dir, reason, operation),
+        false => operation(),
+    }
+}
+
+fn save(
+    config: &mut model::Config,
+    system: &mut Option<model::SystemConfig>,
+    ui_state: &mut UiState,
+) -> Result<(), ()> {
     post_message(ui_state, "Saving file...");
-    match writer::write(&config.filename, config) {
+    let result = backed_up(ui_state, "save", || {
+        boss_rc500::save(config).and_then(|()| match system {
+            Some(system) => boss_rc500::save_system(system),
+            None => Ok(()),
+        })
+    });
+    match result {
         Ok(()) => {
             post_message(ui_state, "File saved!");
-            return Ok(());
+            Ok(())
         }
         Err(e) => {
-            post_message(ui_state, &format!("Error saving to file: {:?}", e));
-            return Err(());
+            post_message(ui_state, &format!("Error saving to file: {}", e));
+            Err(())
         }
     }
 }
 
+/// Push or pull, after asking which RC500 to use if there are several and none was chosen yet.
+fn with_device(ui_state: &mut UiState, action: DeviceAction) {
+    if ui_state.device.is_none() {
+        let devices = io::rc500s(io::backend(ui_state.device_root.as_deref(), None).as_ref()).unwrap_or_default();
+        if devices.len() > 1 {
+            ui_state.chooser = Some(DeviceChooser {
+                devices,
+                action,
+                index: BoundedIndex::default(),
+                state: ListState::default(),
+            });
+            return;
+        }
+    }
+    let _ = match action {
+        DeviceAction::Push => push(ui_state),
+        DeviceAction::Pull => pull(ui_state),
+    };
+}
+
+fn handle_chooser_input(ui_state: &mut UiState, key: KeyEvent) {
+    let Some(chooser) = &mut ui_state.chooser else {
+        return;
+    };
+    let nr_devices = chooser.devices.len();
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => chooser.index.dec(nr_devices),
+        KeyCode::Down | KeyCode::Char('j') => chooser.index.inc(nr_devices),
+        KeyCode::Enter => {
+            let (id, action) = (
+                chooser.devices[chooser.index.get(nr_devices)].id.clone(),
+                chooser.action,
+            );
+            ui_state.chooser = None;
+            ui_state.device = Some(id);
+            with_device(ui_state, action);
+        }
+        KeyCode::Esc | KeyCode::Char('q') => ui_state.chooser = None,
+        _ => {}
+    }
+}
+
 fn push(ui_state: &mut UiState) -> Result<(), ()> {
     post_message(ui_state, "Pushing config to RC500...");
-    match io::push(&ui_state.working_dir) {
+    let backend = io::backend(ui_state.device_root.as_deref(), ui_state.device.as_deref());
+    match backed_up(ui_state, "push", || io::push(backend.as_ref(), &ui_state.working_dir)) {
         Ok(msg) => {
             post_message(ui_state, &msg);
-            return Ok(());
+            Ok(())
         }
         Err(e) => {
-            post_message(ui_state, &format!("Error pushing config: {:?}", e));
-            return Err(());
+            post_message(ui_state, &format!("Error pushing config: {}", e));
+            Err(())
         }
     }
 }
 
 fn pull(ui_state: &mut UiState) -> Result<(), ()> {
     post_message(ui_state, "Pulling config from RC500...");
-    match io::pull(&ui_state.working_dir, true) {
+    let backend = io::backend(ui_state.device_root.as_deref(), ui_state.device.as_deref());
+    match backed_up(ui_state, "pull", || {
+        io::pull(backend.as_ref(), &ui_state.working_dir, true)
+    }) {
         Ok(msg) => {
             post_message(ui_state, &msg);
-            return Ok(());
+            Ok(())
         }
         Err(e) => {
-            post_message(ui_state, &format!("Error pulling config: {:?}", e));
-            return Err(());
+            post_message(ui_state, &format!("Error pulling config: {}", e));
+            Err(())
         }
     }
 }
 
-fn handle_input(config: &mut model::Config, ui_state: &mut UiState, key: KeyEvent) -> Result<(), ()> {
+/// Rearrange memories in the editor. Memories with recorded audio are refused, because moving their audio
+/// cannot be undone by quitting without saving.
+fn apply_plan(config: &mut model::Config, ui_state: &mut UiState, plan: slots::Plan, msg: &str) -> bool {
+    match slots::apply(config, &plan, None) {
+        Ok(()) => {
+            post_message(ui_state, msg);
+            true
+        }
+        Err(e) => {
+            post_message(
+                ui_state,
+                &format!("{} Use rc500 memory from the command line instead.", e),
+            );
+            false
+        }
+    }
+}
+
+/// Whether the reset was asked for twice in a row. The first time, ask to press `d` again.
+fn confirm_reset(
+    ui_state: &mut UiState,
+    pending_reset: Option<(usize, Option<String>)>,
+    reset: (usize, Option<String>),
+) -> bool {
+    if pending_reset.as_ref() == Some(&reset) {
+        return true;
+    }
+    let what = match &reset.1 {
+        Some(menu) => format!("{} of memory {}", menu, reset.0 + 1),
+        None => format!("memory {}", reset.0 + 1),
+    };
+    post_message(
+        ui_state,
+        &format!("Press d again to reset {} to the default settings", what),
+    );
+    ui_state.pending_reset = Some(reset);
+    false
+}
+
+fn handle_input(
+    config: &mut model::Config,
+    system: &mut Option<model::SystemConfig>,
+    ui_state: &mut UiState,
+    key: KeyEvent,
+) -> Result<(), ()> {
     clear_message(ui_state);
+    let pending_reset = ui_state.pending_reset.take();
+    if ui_state.chooser.is_some() {
+        handle_chooser_input(ui_state, key);
+        return Ok(());
+    }
     // Global key mappings
     if ui_state.focus != Focus::Edit {
         match key.code {
             KeyCode::Char('!') => return Err(()),
-            KeyCode::Char('q') => match save(config, ui_state) {
+            KeyCode::Char('q') => match save(config, system, ui_state) {
                 Ok(_) => return Err(()),
                 Err(_) => return Ok(()),
             },
             KeyCode::Char('s') => {
-                let _ = save(config, ui_state);
+                let _ = save(config, system, ui_state);
                 return Ok(());
             }
             KeyCode::Char('^') => {
-                let _ = push(ui_state);
+                with_device(ui_state, DeviceAction::Push);
                 return Ok(());
             }
             KeyCode::Char('*') => {
-                let _ = pull(ui_state);
+                with_device(ui_state, DeviceAction::Pull);
                 return Ok(());
             }
             _ => {}
@@ -250,30 +482,77 @@ fn handle_input(config: &mut model::Config, ui_state: &mut UiState, key: KeyEven
     // Local key mappings
     match ui_state.focus {
         Focus::Memory => match key.code {
-            KeyCode::Up | KeyCode::Char('k') => ui_state.memory.0.dec(nr_memories(config)),
-            KeyCode::Down | KeyCode::Char('j') => ui_state.memory.0.inc(nr_memories(config)),
+            KeyCode::Up | KeyCode::Char('k') => ui_state.memory.0.dec(nr_slots(config, system)),
+            KeyCode::Down | KeyCode::Char('j') => ui_state.memory.0.inc(nr_slots(config, system)),
             KeyCode::Right | KeyCode::Enter | KeyCode::Char('l') => ui_state.focus = Focus::Menu,
             KeyCode::Char('y') => {
-                let memory = get_selected_memory(config, ui_state);
-                ui_state.clipboard = Clipboard::CopiedMemory(memory.clone());
-                post_message(ui_state, "Copied memory to clipboard!");
+                if let Some(memory) = get_selected_memory(config, ui_state) {
+                    ui_state.clipboard = Clipboard::CopiedMemory(memory.id);
+                    post_message(ui_state, "Copied memory to clipboard!");
+                }
             }
-            KeyCode::Char('p') => match &ui_state.clipboard {
-                Clipboard::Empty => {}
-                Clipboard::CopiedMemory(copied) => {
-                    let nr_memories = config.memories.len();
-                    config.memories[ui_state.memory.0.get(nr_memories)].menus = copied.menus.clone();
-                    post_message(ui_state, "Pasted memory in clipboard to selected memory!");
+            KeyCode::Char('p') | KeyCode::Char('w') => {
+                if let (Clipboard::CopiedMemory(copied), Some(memory)) =
+                    (&ui_state.clipboard, get_selected_memory(config, ui_state))
+                {
+                    let (copied, selected) = (*copied, memory.id);
+                    if key.code == KeyCode::Char('p') {
+                        let msg = "Pasted the settings of the memory in clipboard to selected memory!";
+                        apply_plan(config, ui_state, slots::copy(copied, selected..=selected), msg);
+                    } else {
+                        apply_plan(config, ui_state, slots::swap(copied, selected), "Swapped memories!");
+                    }
                 }
-            },
+            }
+            KeyCode::Char('J') | KeyCode::Char('K') => {
+                if let Some(memory) = get_selected_memory(config, ui_state) {
+                    let from = memory.id;
+                    let to = match key.code {
+                        KeyCode::Char('J') if from + 1 < config.memories.len() => from + 1,
+                        KeyCode::Char('K') if from > 0 => from - 1,
+                        _ => return Ok(()),
+                    };
+                    if apply_plan(config, ui_state, slots::move_to(from, to), "Moved memory!") {
+                        ui_state.memory.0.index = to;
+                    }
+                }
+            }
+            KeyCode::Char('d') => {
+                if let Some(memory) = get_selected_memory(config, ui_state) {
+                    let reset = (memory.id, None);
+                    if confirm_reset(ui_state, pending_reset, reset) {
+                        let plan = slots::clear(memory.id);
+                        apply_plan(config, ui_state, plan, "Reset memory to the default settings!");
+                    }
+                }
+            }
+            KeyCode::Char('r') if get_selected_memory(config, ui_state).is_some() => {
+                let menus = get_selected_menus(config, system, ui_state);
+                if let Some(index) = menus.iter().position(|menu| menu.name == "NAME") {
+                    ui_state.menu.0.index = index;
+                    ui_state.focus = Focus::Edit;
+                }
+            }
             _ => {}
         },
         Focus::Menu => match key.code {
-            KeyCode::Up | KeyCode::Char('k') => ui_state.menu.0.dec(nr_menus(config)),
-            KeyCode::Down | KeyCode::Char('j') => ui_state.menu.0.inc(nr_menus(config)),
+            KeyCode::Up | KeyCode::Char('k') => ui_state.menu.0.dec(nr_menus(config, system, ui_state)),
+            KeyCode::Down | KeyCode::Char('j') => ui_state.menu.0.inc(nr_menus(config, system, ui_state)),
             KeyCode::Left | KeyCode::Char('h') => ui_state.focus = Focus::Memory,
+            KeyCode::Char('d') => {
+                let menu_name = get_selected_menu(config, system, ui_state).name.clone();
+                if let Some(memory) = get_selected_memory(config, ui_state) {
+                    let id = memory.id;
+                    if confirm_reset(ui_state, pending_reset, (id, Some(menu_name.clone()))) {
+                        match slots::reset_menu(config, id, &menu_name) {
+                            Ok(()) => post_message(ui_state, "Reset menu to the default settings!"),
+                            Err(e) => post_message(ui_state, &e.to_string()),
+                        }
+                    }
+                }
+            }
             KeyCode::Right | KeyCode::Char('l') | KeyCode::Enter => {
-                let menu = get_selected_menu(config, ui_state);
+                let menu = get_selected_menu(config, system, ui_state);
                 match &menu.content {
                     model::MenuContent::StringValueMenu(_) => ui_state.focus = Focus::Edit,
                     model::MenuContent::KeyValueMenu(_) => ui_state.focus = Focus::Setting,
@@ -282,7 +561,7 @@ fn handle_input(config: &mut model::Config, ui_state: &mut UiState, key: KeyEven
             _ => {}
         },
         Focus::Setting => {
-            let menu = get_selected_menu(config, ui_state);
+            let menu = get_selected_menu(config, system, ui_state);
             match &menu.content {
                 model::MenuContent::StringValueMenu(_) => {
                     // This is an invalid state, so move back
@@ -298,21 +577,22 @@ fn handle_input(config: &mut model::Config, ui_state: &mut UiState, key: KeyEven
             }
         }
         Focus::Edit => {
-            let menu = get_selected_menu_mut(config, ui_state);
+            let is_system = is_system_selected(config, system, ui_state);
+            let menu = get_selected_menu_mut(config, system, ui_state);
+            let params = menu_params(menu, is_system);
             match &mut menu.content {
                 model::MenuContent::KeyValueMenu(ref mut menu) => match key.code {
                     KeyCode::Up => {
                         let setting = get_selected_setting_mut(menu, ui_state);
-                        let key: &str = &setting.key;
-                        let upper_bound = model::BOUNDS.get(key);
-                        match upper_bound {
+                        match setting_param(params, setting) {
                             None => setting.value += 1,
-                            Some(bound) => setting.value = min(*bound, setting.value + 1),
+                            Some(param) => setting.value = min(param.max, setting.value + 1),
                         }
                     }
                     KeyCode::Down => {
                         let setting = get_selected_setting_mut(menu, ui_state);
-                        if setting.value > 0 {
+                        let lower_bound = setting_param(params, setting).map_or(0, |param| param.min);
+                        if setting.value > lower_bound {
                             setting.value -= 1;
                         }
                     }
@@ -343,7 +623,12 @@ fn handle_input(config: &mut model::Config, ui_state: &mut UiState, key: KeyEven
     Ok(())
 }
 
-fn ui<B: Backend>(f: &mut Frame<B>, config: &model::Config, ui_state: &mut UiState) {
+fn ui<B: Backend>(
+    f: &mut Frame<B>,
+    config: &model::Config,
+    system: &Option<model::SystemConfig>,
+    ui_state: &mut UiState,
+) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .margin(2)
@@ -360,7 +645,7 @@ fn ui<B: Backend>(f: &mut Frame<B>, config: &model::Config, ui_state: &mut UiSta
 
     render_meta_info(f, chunks[0], config, ui_state);
     render_help(f, chunks[1], ui_state);
-    render_description(f, chunks[3], config, ui_state);
+    render_description(f, chunks[3], config, system, ui_state);
 
     {
         let chunks = Layout::default()
@@ -375,10 +660,38 @@ fn ui<B: Backend>(f: &mut Frame<B>, config: &model::Config, ui_state: &mut UiSta
             )
             .split(chunks[2]);
 
-        render_memories(f, chunks[0], config, ui_state);
-        render_menus(f, chunks[1], config, ui_state);
-        render_settings(f, chunks[2], config, ui_state);
+        render_memories(f, chunks[0], config, system, ui_state);
+        render_menus(f, chunks[1], config, system, ui_state);
+        render_settings(f, chunks[2], config, system, ui_state);
     }
+    render_chooser(f, chunks[2], ui_state);
+}
+
+fn render_chooser<B: Backend>(f: &mut Frame<B>, rect: Rect, ui_state: &mut UiState) {
+    let Some(chooser) = &mut ui_state.chooser else {
+        return;
+    };
+    let items: Vec<ListItem> = chooser
+        .devices
+        .iter()
+        .map(|dev| ListItem::new(Spans::from(Span::raw(format!("{}  {}", dev.id, dev.path)))))
+        .collect();
+    let area = Rect {
+        x: rect.x + rect.width / 6,
+        y: rect.y + rect.height / 4,
+        width: rect.width * 2 / 3,
+        height: min(rect.height / 2, items.len() as u16 + 2),
+    };
+    chooser.state.select(Some(chooser.index.get(items.len())));
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("CHOOSE AN RC500 (Enter to use it, Esc to cancel)"),
+        )
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+    f.render_widget(Clear, area);
+    f.render_stateful_widget(list, area, &mut chooser.state);
 }
 
 fn render_meta_info<B: Backend>(f: &mut Frame<B>, rect: Rect, _config: &model::Config, ui_state: &mut UiState) {
@@ -387,7 +700,7 @@ fn render_meta_info<B: Backend>(f: &mut Frame<B>, rect: Rect, _config: &model::C
             Span::raw("Working directory: "),
             Span::styled(&ui_state.working_dir, Style::default().fg(Color::Red)),
             // Span::raw(", Tag: '"),
-            // Span::styled(format!("{:?}", &config.suffix), Style::default().fg(Color::Red)),
+            // Span::styled(format!("{:?}", &config.trailer), Style::default().fg(Color::Red)),
         ],
         Style::default(),
     );
@@ -415,7 +728,15 @@ fn render_help<B: Backend>(f: &mut Frame<B>, rect: Rect, ui_state: &mut UiState)
                 Span::styled("y", Style::default().fg(Color::Red)),
                 Span::raw(" to copy a memory, "),
                 Span::styled("p", Style::default().fg(Color::Red)),
-                Span::raw(" to paste a memory, "),
+                Span::raw(" to paste its settings, "),
+                Span::styled("w", Style::default().fg(Color::Red)),
+                Span::raw(" to swap with it, "),
+                Span::styled("J/K", Style::default().fg(Color::Red)),
+                Span::raw(" to move a memory, "),
+                Span::styled("d", Style::default().fg(Color::Red)),
+                Span::raw(" twice to reset a memory or menu, "),
+                Span::styled("r", Style::default().fg(Color::Red)),
+                Span::raw(" to rename it, "),
                 Span::styled("^", Style::default().fg(Color::Red)),
                 Span::raw(" to push to RC500, "),
                 Span::styled("*", Style::default().fg(Color::Red)),
@@ -430,36 +751,33 @@ fn render_help<B: Backend>(f: &mut Frame<B>, rect: Rect, ui_state: &mut UiState)
     f.render_widget(help_message, rect);
 }
 
-fn get_key_name(setting: &model::UntypedKeyValue) -> &str {
-    let key: &str = &setting.key;
-    model::DISPLAY_KEYS.get(key).unwrap_or(&key)
+fn get_key_name<'a>(params: Option<&'static schema::Params>, setting: &'a model::UntypedKeyValue) -> &'a str {
+    match setting_param(params, setting) {
+        Some(param) => param.name,
+        None => &setting.key,
+    }
 }
 
-fn get_value_name(setting: &model::UntypedKeyValue) -> String {
-    let default: String = format!("{}", setting.value);
-    let key: &str = &setting.key;
-    let result = model::DISPLAY_VALUES
-        .get(key)
-        .and_then(|&values| values.get(setting.value))
-        .map(|&s| s.to_string())
-        .unwrap_or(default);
-    result
+fn get_value_name(params: Option<&'static schema::Params>, setting: &model::UntypedKeyValue) -> String {
+    match setting_param(params, setting) {
+        Some(param) => param.display_value(setting.value),
+        None => format!("{}", setting.value),
+    }
 }
 
-pub fn get_description(setting: &model::UntypedKeyValue) -> Text {
-    let base_text = match descriptions::DESCRIPTIONS.get(&setting.key) {
-        Some(text) => text,
-        None => "-",
+pub fn get_description(params: Option<&'static schema::Params>, setting: &model::UntypedKeyValue) -> Text<'static> {
+    let param = setting_param(params, setting);
+    let base_text = match param {
+        Some(param) if !param.description.is_empty() => param.description,
+        _ => "-",
     };
-    let value_name = get_value_name(setting);
-    let by_value = descriptions::DESCRIPTIONS_BY_VALUE
-        .get(&setting.key)
-        .and_then(|array| array.get(setting.value));
+    let value_name = get_value_name(params, setting);
+    let by_value = param.and_then(|param| param.value_description(setting.value));
 
     let mut text = Text::raw(base_text);
     match by_value {
         None => {}
-        Some(&value_text) => {
+        Some(value_text) => {
             text.extend(Text::raw("\n\n"));
             text.extend(Text::styled(
                 format!("{}: ", value_name),
@@ -471,7 +789,13 @@ pub fn get_description(setting: &model::UntypedKeyValue) -> Text {
     text
 }
 
-fn render_description<B: Backend>(f: &mut Frame<B>, rect: Rect, config: &model::Config, ui_state: &mut UiState) {
+fn render_description<B: Backend>(
+    f: &mut Frame<B>,
+    rect: Rect,
+    config: &model::Config,
+    system: &Option<model::SystemConfig>,
+    ui_state: &mut UiState,
+) {
     // Render message if existing, otherwise render setting description
     match &ui_state.message {
         Some(msg) => {
@@ -484,37 +808,56 @@ fn render_description<B: Backend>(f: &mut Frame<B>, rect: Rect, config: &model::
             f.render_widget(msg, rect);
         }
         None => {
-            let selected_menu = get_selected_menu(config, ui_state);
-            match &selected_menu.content {
-                model::MenuContent::KeyValueMenu(selected_menu) => {
-                    let selected_setting = get_selected_setting(selected_menu, ui_state);
-                    let text = get_description(&selected_setting);
-                    let msg = Paragraph::new(text)
-                        .block(Block::default().title("DESCRIPTION").borders(Borders::ALL))
-                        .wrap(Wrap { trim: false });
-                    f.render_widget(msg, rect);
+            let selected_menu = get_selected_menu(config, system, ui_state);
+            let params = menu_params(selected_menu, is_system_selected(config, system, ui_state));
+            if let model::MenuContent::KeyValueMenu(settings) = &selected_menu.content {
+                let selected_setting = get_selected_setting(settings, ui_state);
+                let mut text = get_description(params, selected_setting);
+                for finding in get_selected_findings(config, ui_state)
+                    .iter()
+                    .filter(|finding| finding.concerns(&selected_menu.name, &selected_setting.key))
+                {
+                    text.extend(Text::raw("\n"));
+                    text.extend(Text::styled(finding.to_string(), severity_style(finding.severity)));
                 }
-                _ => {}
+                let msg = Paragraph::new(text)
+                    .block(Block::default().title("DESCRIPTION").borders(Borders::ALL))
+                    .wrap(Wrap { trim: false });
+                f.render_widget(msg, rect);
             }
         }
     }
 }
 
-fn render_memories<B: Backend>(f: &mut Frame<B>, rect: Rect, config: &model::Config, ui_state: &mut UiState) {
+fn render_memories<B: Backend>(
+    f: &mut Frame<B>,
+    rect: Rect,
+    config: &model::Config,
+    system: &Option<model::SystemConfig>,
+    ui_state: &mut UiState,
+) {
     let items_style = Style::default();
-    let memories: Vec<ListItem> = config
+    let mut memories: Vec<ListItem> = config
         .memories
         .iter()
         .map(|m| {
             let name = model::get_memory_name(m);
             let is_empty = if model::is_memory_empty(m) { "(empty)" } else { "" };
-            let content = vec![Spans::from(Span::raw(format!("{}: {} {}", m.id + 1, name, is_empty)))];
-            ListItem::new(content).style(items_style)
+            let worst = lint::lint_memory(m, &ui_state.lint_allow)
+                .iter()
+                .map(|finding| finding.severity)
+                .max();
+            let mut content = vec![Span::raw(format!("{}: {} {}", m.id + 1, name, is_empty))];
+            content.extend(worst.map(|severity| Span::styled("!", severity_style(severity))));
+            ListItem::new(Spans::from(content)).style(items_style)
         })
         .collect();
+    if system.is_some() {
+        memories.push(ListItem::new(Spans::from(Span::raw("SYSTEM"))).style(items_style));
+    }
     ui_state
         .memory_state
-        .select(Some(ui_state.memory.0.get(nr_memories(config))));
+        .select(Some(ui_state.memory.0.get(nr_slots(config, system))));
     let selected_style = items_style.add_modifier(Modifier::REVERSED);
     let memories = List::new(memories)
         .block(Block::default().borders(Borders::ALL).title("MEMORY SLOTS"))
@@ -522,21 +865,27 @@ fn render_memories<B: Backend>(f: &mut Frame<B>, rect: Rect, config: &model::Con
     f.render_stateful_widget(memories, rect, &mut ui_state.memory_state);
 }
 
-fn render_menus<B: Backend>(f: &mut Frame<B>, rect: Rect, config: &model::Config, ui_state: &mut UiState) {
+fn render_menus<B: Backend>(
+    f: &mut Frame<B>,
+    rect: Rect,
+    config: &model::Config,
+    system: &Option<model::SystemConfig>,
+    ui_state: &mut UiState,
+) {
     let items_style = match ui_state.focus {
         Focus::Memory => Style::default().add_modifier(Modifier::DIM).fg(Color::DarkGray),
         _ => Style::default(),
     };
-    let selected_memory = get_selected_memory(config, ui_state);
-    let menus: Vec<ListItem> = selected_memory
-        .menus
+    let menus: Vec<ListItem> = get_selected_menus(config, system, ui_state)
         .iter()
         .map(|m| {
-            let content = vec![Spans::from(Span::raw(format!("{}", m.name)))];
+            let content = vec![Spans::from(Span::raw(m.name.to_string()))];
             ListItem::new(content).style(items_style)
         })
         .collect();
-    ui_state.menu_state.select(Some(ui_state.menu.0.get(nr_menus(config))));
+    ui_state
+        .menu_state
+        .select(Some(ui_state.menu.0.get(nr_menus(config, system, ui_state))));
     let selected_style = items_style.add_modifier(Modifier::REVERSED);
     let menus = List::new(menus)
         .block(Block::default().borders(Borders::ALL).title("MENUS"))
@@ -544,30 +893,43 @@ fn render_menus<B: Backend>(f: &mut Frame<B>, rect: Rect, config: &model::Config
     f.render_stateful_widget(menus, rect, &mut ui_state.menu_state);
 }
 
-fn render_settings<B: Backend>(f: &mut Frame<B>, rect: Rect, config: &model::Config, ui_state: &mut UiState) {
+fn render_settings<B: Backend>(
+    f: &mut Frame<B>,
+    rect: Rect,
+    config: &model::Config,
+    system: &Option<model::SystemConfig>,
+    ui_state: &mut UiState,
+) {
     let items_style = match ui_state.focus {
         Focus::Memory | Focus::Menu => Style::default().add_modifier(Modifier::DIM).fg(Color::DarkGray),
         Focus::Setting | Focus::Edit => Style::default(),
     };
-    let selected_menu = get_selected_menu(config, ui_state);
+    let selected_menu = get_selected_menu(config, system, ui_state);
+    let params = menu_params(selected_menu, is_system_selected(config, system, ui_state));
+    let findings = get_selected_findings(config, ui_state);
     match &selected_menu.content {
-        model::MenuContent::KeyValueMenu(selected_menu) => {
-            let settings: Vec<ListItem> = selected_menu
+        model::MenuContent::KeyValueMenu(menu_settings) => {
+            let settings: Vec<ListItem> = menu_settings
                 .settings
                 .iter()
                 .map(|s| {
-                    let display_key: &str = get_key_name(&s);
-                    let display_value: String = get_value_name(&s);
-                    let content = vec![
+                    let display_key: &str = get_key_name(params, s);
+                    let display_value: String = get_value_name(params, s);
+                    let mut content = vec![
                         Span::styled(format!("{} = ", display_key), items_style),
                         Span::styled(display_value, items_style.fg(Color::Blue)),
                     ];
+                    let concerned: Vec<&lint::Finding> = findings
+                        .iter()
+                        .filter(|finding| finding.concerns(&selected_menu.name, &s.key))
+                        .collect();
+                    content.extend(lint_marker(&concerned));
                     ListItem::new(Spans::from(content))
                 })
                 .collect();
             ui_state
                 .setting_state
-                .select(Some(ui_state.setting.0.get(selected_menu.settings.len())));
+                .select(Some(ui_state.setting.0.get(menu_settings.settings.len())));
             let selected_style = if ui_state.focus == Focus::Edit {
                 items_style.add_modifier(Modifier::REVERSED).fg(Color::Magenta)
             } else {
@@ -583,7 +945,7 @@ fn render_settings<B: Backend>(f: &mut Frame<B>, rect: Rect, config: &model::Con
             let mut style = items_style;
             if ui_state.focus == Focus::Edit {
                 style = Style::default().fg(Color::Red);
-                value = value + "_"
+                value += "_"
             }
             let text = Text::from(Spans::from(vec![Span::styled(value, style)]));
             let msg = Paragraph::new(text).block(Block::default().title("NAME").borders(Borders::ALL));
@@ -0,0 +1,144 @@
+//! The errors of the library, by category, so callers can tell a missing device from a damaged file.
+//!
+//! Errors that fit none of the categories, like a merge conflict, are [`Rc500Error::Other`]. Plain messages
+//! convert to it, and an `Rc500Error` converts back to its message where a plain message is expected.
+
+use serde::Serialize;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, thiserror::Error)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Rc500Error {
+    /// No RC500 is connected, or not the one that was asked for.
+    #[error("{message}")]
+    NoDevice { message: String },
+    /// A memory or system file could not be understood.
+    #[error("{}", describe_parse_error(.file, .message, .location))]
+    Parse {
+        file: String,
+        message: String,
+        #[serde(flatten)]
+        location: Option<Location>,
+    },
+    /// A value is out of range, or a config would be rejected by the RC500.
+    #[error("{message}")]
+    InvalidValue { message: String },
+    /// Reading or writing a file failed, or a file was not written because it would replace an existing one.
+    #[error("{message}")]
+    Io { message: String },
+    #[error("{message}")]
+    Other { message: String },
+}
+
+impl Rc500Error {
+    pub fn no_device(message: impl Into<String>) -> Rc500Error {
+        Rc500Error::NoDevice {
+            message: message.into(),
+        }
+    }
+
+    pub fn parse(file: &str, message: impl Into<String>) -> Rc500Error {
+        Rc500Error::Parse {
+            file: file.to_string(),
+            message: message.into(),
+            location: None,
+        }
+    }
+
+    pub fn invalid_value(message: impl Into<String>) -> Rc500Error {
+        Rc500Error::InvalidValue {
+            message: message.into(),
+        }
+    }
+
+    pub fn io(message: impl Into<String>) -> Rc500Error {
+        Rc500Error::Io {
+            message: message.into(),
+        }
+    }
+
+    /// The same error, with the message prefixed by what was being done.
+    pub fn context(mut self, context: &str) -> Rc500Error {
+        let message = match &mut self {
+            Rc500Error::NoDevice { message }
+            | Rc500Error::Parse { message, .. }
+            | Rc500Error::InvalidValue { message }
+            | Rc500Error::Io { message }
+            | Rc500Error::Other { message } => message,
+        };
+        *message = format!("{}. {}", context, message);
+        self
+    }
+
+    /// The message without the file it is about.
+    pub fn message(&self) -> &str {
+        match self {
+            Rc500Error::NoDevice { message }
+            | Rc500Error::Parse { message, .. }
+            | Rc500Error::InvalidValue { message }
+            | Rc500Error::Io { message }
+            | Rc500Error::Other { message } => message,
+        }
+    }
+}
+
+/// Where in a file a parse error is.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Location {
+    /// Counting from 1
+    pub line: u32,
+    /// Counting from 1, in characters
+    pub column: u32,
+    /// The setting the error is about, like 3/TRACK1/PlyLvl, if it is about one
+    pub path: Option<String>,
+    /// The line of the file
+    pub snippet: String,
+}
+
+/// Show a parse error like a compiler would, with the line of the file and a marker under the column.
+fn describe_parse_error(file: &str, message: &str, location: &Option<Location>) -> String {
+    let location = match location {
+        None => return format!("{}: {}", file, message),
+        Some(location) => location,
+    };
+    let mut text = format!("{}:{}:{}: {}", file, location.line, location.column, message);
+    if let Some(path) = &location.path {
+        text.push_str(&format!("\n    at {}", path));
+    }
+    let number = location.line.to_string();
+    let margin = " ".repeat(number.len());
+    let indent: String = location
+        .snippet
+        .chars()
+        .take(location.column.saturating_sub(1) as usize)
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect();
+    text.push_str(&format!(
+        "\n {} |\n {} | {}\n {} | {}^",
+        margin,
+        number,
+        location.snippet.trim_end(),
+        margin,
+        indent
+    ));
+    text
+}
+
+impl From<String> for Rc500Error {
+    fn from(message: String) -> Rc500Error {
+        Rc500Error::Other { message }
+    }
+}
+
+impl From<&str> for Rc500Error {
+    fn from(message: &str) -> Rc500Error {
+        Rc500Error::Other {
+            message: message.to_string(),
+        }
+    }
+}
+
+impl From<Rc500Error> for String {
+    fn from(error: Rc500Error) -> String {
+        error.to_string()
+    }
+}
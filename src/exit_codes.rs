@@ -3,3 +3,22 @@ pub type ExitCode = i32;
 
 pub const OK: ExitCode = 0;
 pub const ERROR: ExitCode = 1;
+/// No RC500 was found, or not the one given with --device.
+pub const NO_DEVICE: ExitCode = 3;
+/// A memory or system file could not be parsed.
+pub const PARSE: ExitCode = 4;
+/// A value was out of range, or the config would be rejected by the RC500.
+pub const INVALID_VALUE: ExitCode = 5;
+/// Reading or writing a file failed.
+pub const IO: ExitCode = 6;
+
+pub fn for_error(error: &boss_rc500::Rc500Error) -> ExitCode {
+    use boss_rc500::Rc500Error;
+    match error {
+        Rc500Error::NoDevice { .. } => NO_DEVICE,
+        Rc500Error::Parse { .. } => PARSE,
+        Rc500Error::InvalidValue { .. } => INVALID_VALUE,
+        Rc500Error::Io { .. } => IO,
+        Rc500Error::Other { .. } => ERROR,
+    }
+}
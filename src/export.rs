@@ -0,0 +1,222 @@
+//! Conversion of the memories to and from JSON, YAML and TOML, for review by humans.
+//!
+//! Settings are written with their display name and label, like `stop: Fade Out` instead of `StpMod: 1`, in
+//! the order in which they appear in the memory file. Settings that are not in the schema keep their tag
+//! name and number. Importing applies the settings to an existing config, so that the result has every menu
+//! and key the device expects, even if the text leaves some of them out.
+
+use crate::error::Rc500Error;
+use crate::model;
+use crate::schema;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Yaml,
+    Toml,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text.to_ascii_lowercase().as_str() {
+            "json" => Ok(Format::Json),
+            "yaml" | "yml" => Ok(Format::Yaml),
+            "toml" => Ok(Format::Toml),
+            _ => Err(format!("Unknown format '{}', expected json, yaml or toml", text)),
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Format::Json => write!(f, "json"),
+            Format::Yaml => write!(f, "yaml"),
+            Format::Toml => write!(f, "toml"),
+        }
+    }
+}
+
+impl Format {
+    /// The format that belongs to the extension of the file name, if any.
+    pub fn from_file_name(file_name: &str) -> Option<Format> {
+        file_name
+            .rsplit_once('.')
+            .and_then(|(_, extension)| extension.parse().ok())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Export {
+    pub memories: Vec<ExportedMemory>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExportedMemory {
+    /// The memory number as shown on the device, counting from 1
+    pub memory: usize,
+    pub name: String,
+    /// The settings by display name, per menu
+    #[serde(flatten)]
+    pub menus: IndexMap<String, IndexMap<String, Value>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Value {
+    Number(usize),
+    Label(String),
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Number(value) => write!(f, "{}", value),
+            Value::Label(label) => write!(f, "{}", label),
+        }
+    }
+}
+
+/// The key of a parameter in the text formats: its display name in snake case.
+pub fn display_key(param: &schema::Param) -> String {
+    param.name.to_lowercase().replace(' ', "_")
+}
+
+fn menu_key(menu: &str) -> String {
+    menu.to_lowercase()
+}
+
+fn export_value(param: Option<&schema::Param>, value: usize) -> Value {
+    // Only use labels that map back to the same value
+    let label = param.and_then(|param| param.labels).and_then(|labels| {
+        labels
+            .get(value)
+            .filter(|label| labels.iter().position(|l| l == *label) == Some(value))
+    });
+    match label {
+        Some(label) => Value::Label(label.to_string()),
+        None => Value::Number(value),
+    }
+}
+
+pub fn export(config: &model::Config) -> Export {
+    let memories = config
+        .memories
+        .iter()
+        .map(|memory| {
+            let mut menus = IndexMap::new();
+            for menu in &memory.menus {
+                if let model::MenuContent::KeyValueMenu(settings) = &menu.content {
+                    let values = settings
+                        .settings
+                        .iter()
+                        .map(|setting| {
+                            let param = schema::param(&menu.name, &setting.key);
+                            let key = param.map_or_else(|| setting.key.clone(), display_key);
+                            (key, export_value(param, setting.value))
+                        })
+                        .collect();
+                    menus.insert(menu_key(&menu.name), values);
+                }
+            }
+            ExportedMemory {
+                memory: memory.id + 1,
+                name: model::get_memory_name(memory).to_string(),
+                menus,
+            }
+        })
+        .collect();
+    Export { memories }
+}
+
+pub fn to_string(export: &Export, format: Format) -> Result<String, Rc500Error> {
+    match format {
+        Format::Json => serde_json::to_string_pretty(export).map_err(|e| e.to_string()),
+        Format::Yaml => serde_yaml::to_string(export).map_err(|e| e.to_string()),
+        Format::Toml => toml::to_string(export).map_err(|e| e.to_string()),
+    }
+    .map_err(|e| Rc500Error::from(format!("Could not write the {} text: {}", format, e)))
+}
+
+/// Read the text of an export. The file of a parse error is the format, callers that know the file can
+/// replace it.
+pub fn from_str(text: &str, format: Format) -> Result<Export, Rc500Error> {
+    match format {
+        Format::Json => serde_json::from_str(text).map_err(|e| e.to_string()),
+        Format::Yaml => serde_yaml::from_str(text).map_err(|e| e.to_string()),
+        Format::Toml => toml::from_str(text).map_err(|e| e.to_string()),
+    }
+    .map_err(|e| Rc500Error::parse(&format!("{} text", format), e))
+}
+
+/// Apply the exported settings to the config. Every memory, menu and key in the text must exist in the
+/// config, and every value must be valid. Nothing is changed if any of them is not.
+pub fn import(config: &mut model::Config, export: &Export) -> Result<(), Rc500Error> {
+    let mut imported = config.clone();
+    for exported in &export.memories {
+        let memory = imported
+            .memories
+            .iter_mut()
+            .find(|memory| memory.id + 1 == exported.memory)
+            .ok_or_else(|| Rc500Error::invalid_value(format!("There is no memory {}", exported.memory)))?;
+        import_memory(memory, exported)
+            .map_err(|e| Rc500Error::invalid_value(format!("Memory {}: {}", exported.memory, e)))?;
+    }
+    *config = imported;
+    Ok(())
+}
+
+fn import_memory(memory: &mut model::Memory, exported: &ExportedMemory) -> Result<(), String> {
+    for menu in memory.menus.iter_mut() {
+        let values = exported.menus.get(&menu_key(&menu.name));
+        match &mut menu.content {
+            model::MenuContent::StringValueMenu(name) => {
+                if exported.name.chars().count() > model::STRING_MAX_WIDTH
+                    || !exported.name.chars().all(model::is_name_char)
+                {
+                    return Err(format!("'{}' is not a valid name", exported.name));
+                }
+                name.value = exported.name.trim_end().to_string();
+            }
+            model::MenuContent::KeyValueMenu(settings) => {
+                let values = match values {
+                    Some(values) => values,
+                    None => continue,
+                };
+                for (key, value) in values {
+                    let setting = settings
+                        .settings
+                        .iter_mut()
+                        .find(|setting| {
+                            schema::param(&menu.name, &setting.key)
+                                .map_or_else(|| setting.key == *key, |param| display_key(param) == *key)
+                        })
+                        .ok_or_else(|| format!("{} has no setting {}", menu.name, key))?;
+                    setting.value = match schema::param(&menu.name, &setting.key) {
+                        Some(param) => param
+                            .parse_value(&value.to_string())
+                            .map_err(|e| format!("{}/{}: {}", menu.name, key, e.message()))?,
+                        None => match value {
+                            Value::Number(value) => *value,
+                            Value::Label(label) => {
+                                return Err(format!("{}/{}: '{}' is not a number", menu.name, key, label))
+                            }
+                        },
+                    };
+                }
+            }
+        }
+    }
+    for menu in exported.menus.keys() {
+        if !memory.menus.iter().any(|m| menu_key(&m.name) == *menu) {
+            return Err(format!("There is no menu {}", menu));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,166 @@
+//! Helpers that let git show and merge memory files by their settings instead of as binary files.
+//!
+//! To use them, add to `.gitattributes`:
+//!
+//! ```text
+//! *.RC0 diff=rc500 merge=rc500
+//! ```
+//!
+//! and to the git config:
+//!
+//! ```text
+//! [diff "rc500"]
+//!     textconv = rc500 git-textconv
+//! [merge "rc500"]
+//!     name = RC500 memory merge
+//!     driver = rc500 git-merge %O %A %B
+//! ```
+
+use crate::model;
+use crate::schema;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Every setting on its own line, like `Memory 7 / TRACK1 / Stop = Fade Out`, ordered by memory and then
+/// as in the file. Lines do not contain the name of the memory, so renaming a memory changes a single line.
+pub fn textconv(config: &model::Config) -> String {
+    let mut memories: Vec<&model::Memory> = config.memories.iter().collect();
+    memories.sort_by_key(|memory| memory.id);
+    let mut lines = Vec::new();
+    for memory in memories {
+        for menu in &memory.menus {
+            match &menu.content {
+                model::MenuContent::StringValueMenu(name) => {
+                    lines.push(format!("Memory {} / {} = {}", memory.id + 1, menu.name, name.value))
+                }
+                model::MenuContent::KeyValueMenu(settings) => {
+                    for setting in &settings.settings {
+                        let (name, value) = match schema::param(&menu.name, &setting.key) {
+                            Some(param) => (param.name, param.display_value(setting.value)),
+                            None => (setting.key.as_str(), setting.value.to_string()),
+                        };
+                        lines.push(format!(
+                            "Memory {} / {} / {} = {}",
+                            memory.id + 1,
+                            menu.name,
+                            name,
+                            value
+                        ));
+                    }
+                }
+            }
+        }
+    }
+    lines.join("\n")
+}
+
+/// A setting that was changed in different ways on both sides of a merge.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Conflict {
+    /// The memory number as shown on the device, counting from 1
+    pub memory: usize,
+    pub menu: String,
+    pub key: String,
+    pub base: Option<String>,
+    pub ours: Option<String>,
+    pub theirs: Option<String>,
+}
+
+impl fmt::Display for Conflict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let show = |value: &Option<String>| value.clone().unwrap_or_else(|| "(missing)".to_string());
+        write!(
+            f,
+            "Memory {} / {} / {}: base {}, ours {}, theirs {}",
+            self.memory,
+            self.menu,
+            self.key,
+            show(&self.base),
+            show(&self.ours),
+            show(&self.theirs)
+        )
+    }
+}
+
+/// The settings of a config by memory id, menu and key, as text. The key of a NAME is the menu name.
+fn values(config: &model::Config) -> BTreeMap<(usize, String, String), String> {
+    let mut values = BTreeMap::new();
+    for memory in &config.memories {
+        for menu in &memory.menus {
+            match &menu.content {
+                model::MenuContent::StringValueMenu(name) => {
+                    values.insert((memory.id, menu.name.clone(), menu.name.clone()), name.value.clone());
+                }
+                model::MenuContent::KeyValueMenu(settings) => {
+                    for setting in &settings.settings {
+                        values.insert(
+                            (memory.id, menu.name.clone(), setting.key.clone()),
+                            setting.value.to_string(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+    values
+}
+
+/// Merge the changes from `base` to `theirs` into `ours`, setting by setting. Settings that were changed on
+/// both sides to different values keep our value and are reported as conflicts. Settings can only be
+/// changed, not added or removed: a memory, menu or key that is missing on one side is a conflict if the
+/// other side changed it.
+pub fn merge(base: &model::Config, ours: &model::Config, theirs: &model::Config) -> (model::Config, Vec<Conflict>) {
+    let base_values = values(base);
+    let our_values = values(ours);
+    let their_values = values(theirs);
+    let mut keys: Vec<&(usize, String, String)> = our_values.keys().collect();
+    keys.extend(their_values.keys().filter(|key| !our_values.contains_key(*key)));
+
+    let mut merged = ours.clone();
+    let mut conflicts = Vec::new();
+    for key in keys {
+        let (b, o, t) = (base_values.get(key), our_values.get(key), their_values.get(key));
+        // None if both sides changed the setting
+        let result = if o == t || t == b {
+            Some(o)
+        } else if o == b {
+            Some(t)
+        } else {
+            None
+        };
+        let (id, menu, setting) = key;
+        match (result, o) {
+            (Some(result), _) if result == o => {}
+            (Some(Some(result)), Some(_)) => set(&mut merged, *id, menu, setting, result),
+            _ => conflicts.push(Conflict {
+                memory: id + 1,
+                menu: menu.clone(),
+                key: setting.clone(),
+                base: b.cloned(),
+                ours: o.cloned(),
+                theirs: t.cloned(),
+            }),
+        }
+    }
+    if theirs.trailer.is_newer_than(&merged.trailer) {
+        merged.trailer = theirs.trailer.clone();
+    }
+    (merged, conflicts)
+}
+
+fn set(config: &mut model::Config, id: usize, menu_name: &str, key: &str, value: &str) {
+    let memory = match config.memories.iter_mut().find(|memory| memory.id == id) {
+        Some(memory) => memory,
+        None => return,
+    };
+    if let Some(menu) = memory.menus.iter_mut().find(|menu| menu.name == menu_name) {
+        match &mut menu.content {
+            model::MenuContent::StringValueMenu(name) => name.value = value.to_string(),
+            model::MenuContent::KeyValueMenu(_) => {
+                if let (Some(setting), Ok(value)) = (model::get_setting_mut(memory, menu_name, key), value.parse()) {
+                    setting.value = value;
+                }
+            }
+        }
+    }
+}
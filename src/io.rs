@@ -1,70 +1,424 @@
+use crate::backup;
+use crate::buffers;
+use crate::diff;
+use crate::error::Rc500Error;
+use crate::model;
+use crate::reader;
+use crate::slots;
+use crate::validate;
+use crate::writer;
 use std::fs;
 use std::path::Path;
 use std::path::PathBuf;
+
+#[cfg(windows)]
 use windows::core::Result as WindowsResult;
+#[cfg(windows)]
 use windows::Devices::Enumeration::{DeviceClass, DeviceInformation};
+#[cfg(windows)]
 use windows::Devices::Portable::StorageDevice;
 
 const DEVICE_NAME: &str = "BOSS_RC-500";
 
-pub fn pull(working_dir: &str, overwrite: bool) -> Result<String, String> {
-    match list_devices() {
-        Err(e) => Err(format!("Could not retrieve any device info: {:?}", e).to_string()),
-        Ok(devs) => {
-            let dev = pick_device(&devs)?;
-            do_pull(&dev.path, working_dir)
+/// Abstracts over the ways an RC500 can be reached, so the sync logic does not need to know whether it is
+/// talking to a connected pedal or to a plain directory that mimics one.
+pub trait DeviceBackend {
+    /// List all devices this backend can reach. Devices that are not an RC500 are filtered out later.
+    fn list_devices(&self) -> Result<Vec<Device>, Rc500Error>;
+
+    /// The directory that contains the ROLAND folder of the device.
+    fn root(&self, dev: &Device) -> Result<PathBuf, Rc500Error> {
+        Ok(PathBuf::from(&dev.path))
+    }
+
+    fn read_file(&self, dev: &Device, name: &str) -> Result<Vec<u8>, Rc500Error> {
+        let path = data_dir(&self.root(dev)?).join(name);
+        fs::read(&path).map_err(|e| Rc500Error::io(format!("Could not read {:?} from device: {}", path, e)))
+    }
+
+    /// The WAVE folder with the recorded audio of the memories.
+    fn wave_dir(&self, dev: &Device) -> Result<PathBuf, Rc500Error> {
+        Ok(self.root(dev)?.join("ROLAND").join("WAVE"))
+    }
+
+    fn has_file(&self, dev: &Device, name: &str) -> bool {
+        self.root(dev).is_ok_and(|root| data_dir(&root).join(name).is_file())
+    }
+
+    /// Write through a temporary file that replaces the file when it is complete, so an interrupted write
+    /// does not leave a truncated file behind. A temporary file left by an earlier interrupted write is
+    /// replaced.
+    fn write_file(&self, dev: &Device, name: &str, content: &[u8]) -> Result<(), Rc500Error> {
+        let path = data_dir(&self.root(dev)?).join(name);
+        let temp = path.with_extension("TMP");
+        if temp.exists() {
+            fs::remove_file(&temp)
+                .map_err(|e| Rc500Error::io(format!("Could not remove {:?} from device: {}", temp, e)))?;
         }
+        fs::write(&temp, content)
+            .map_err(|e| Rc500Error::io(format!("Could not write {:?} to device: {}", temp, e)))?;
+        fs::rename(&temp, &path).map_err(|e| {
+            let _ = fs::remove_file(&temp);
+            Rc500Error::io(format!("Could not replace {:?} on device: {}", path, e))
+        })
+    }
+
+    fn remove_file(&self, dev: &Device, name: &str) -> Result<(), Rc500Error> {
+        let path = data_dir(&self.root(dev)?).join(name);
+        fs::remove_file(&path).map_err(|e| Rc500Error::io(format!("Could not remove {:?} from device: {}", path, e)))
+    }
+}
+
+/// The RC500s that are connected to this machine, found through the platform's device discovery.
+pub struct ConnectedDevices;
+
+impl DeviceBackend for ConnectedDevices {
+    fn list_devices(&self) -> Result<Vec<Device>, Rc500Error> {
+        list_devices()
     }
 }
 
-pub fn push(working_dir: &str) -> Result<String, String> {
-    match list_devices() {
-        Err(e) => Err(format!("Could not retrieve any device info: {:?}", e).to_string()),
-        Ok(devs) => {
-            let dev = pick_device(&devs)?;
-            do_push(&dev.path, working_dir)
+/// A plain directory laid out like the root of an RC500 (containing ROLAND/DATA/), treated as a device.
+pub struct DirectoryDevice {
+    pub root: PathBuf,
+}
+
+impl DeviceBackend for DirectoryDevice {
+    fn list_devices(&self) -> Result<Vec<Device>, Rc500Error> {
+        if !data_dir(&self.root).is_dir() {
+            return Err(Rc500Error::no_device(format!(
+                "{:?} does not contain a ROLAND/DATA directory",
+                self.root
+            )));
         }
+        Ok(vec![Device {
+            id: self.root.to_string_lossy().to_string(),
+            name: DEVICE_NAME.to_string(),
+            path: self.root.to_string_lossy().to_string(),
+        }])
     }
 }
 
-#[derive(Clone, Debug)]
-struct Device {
-    name: String,
-    path: String,
+/// Another backend restricted to the device with the given id, for when several RC500s are connected.
+pub struct SelectedDevice {
+    pub inner: Box<dyn DeviceBackend>,
+    pub id: String,
 }
 
-fn pick_device(devs: &Vec<Device>) -> Result<Device, String> {
-    let rc500s: Vec<&Device> = devs.into_iter().filter(|dev| dev.name == DEVICE_NAME).collect();
-    match rc500s[..] {
-        [] => Err("No RC500 found".to_string()),
-        [dev] => Ok(dev.clone()),
-        _ => Err(format!(
-            "Found {} RC500 devices. Don't know which to choose.",
-            rc500s.len()
-        )),
+impl DeviceBackend for SelectedDevice {
+    fn list_devices(&self) -> Result<Vec<Device>, Rc500Error> {
+        let devs = rc500s(self.inner.as_ref())?;
+        match devs.iter().find(|dev| dev.id == self.id) {
+            Some(dev) => Ok(vec![dev.clone()]),
+            None => Err(Rc500Error::no_device(format!(
+                "No RC500 with id {:?}. Found: {}",
+                self.id,
+                device_ids(&devs)
+            ))),
+        }
+    }
+
+    fn root(&self, dev: &Device) -> Result<PathBuf, Rc500Error> {
+        self.inner.root(dev)
+    }
+
+    fn read_file(&self, dev: &Device, name: &str) -> Result<Vec<u8>, Rc500Error> {
+        self.inner.read_file(dev, name)
+    }
+
+    fn wave_dir(&self, dev: &Device) -> Result<PathBuf, Rc500Error> {
+        self.inner.wave_dir(dev)
+    }
+
+    fn has_file(&self, dev: &Device, name: &str) -> bool {
+        self.inner.has_file(dev, name)
+    }
+
+    fn write_file(&self, dev: &Device, name: &str, content: &[u8]) -> Result<(), Rc500Error> {
+        self.inner.write_file(dev, name, content)
+    }
+
+    fn remove_file(&self, dev: &Device, name: &str) -> Result<(), Rc500Error> {
+        self.inner.remove_file(dev, name)
     }
 }
 
-pub fn print_devices() -> Result<(), String> {
-    let fetch_infos = DeviceInformation::FindAllAsyncDeviceClass(DeviceClass::PortableStorageDevice)
-        .map_err(|e| format!("{:?}", e))?;
-    let infos = fetch_infos.get().map_err(|e| format!("{:?}", e))?;
-    let nr_devs = infos.Size().map_err(|e| format!("{:?}", e))?;
-    println!("Found {:?} devices", nr_devs);
-    let mut result: Vec<Device> = Vec::new();
-    for info in infos {
-        match scan_device(info) {
-            Err(e) => println!("Error occurred while retrieving device info: {:?}", e),
-            Ok(dev) => result.push(dev),
+/// Use the directory at `device_root` as device if given, otherwise look for connected devices. With `device`,
+/// only the device with that id is used.
+pub fn backend(device_root: Option<&str>, device: Option<&str>) -> Box<dyn DeviceBackend> {
+    let backend: Box<dyn DeviceBackend> = match device_root {
+        Some(root) => Box::new(DirectoryDevice {
+            root: PathBuf::from(root),
+        }),
+        None => Box::new(ConnectedDevices),
+    };
+    match device {
+        Some(id) => Box::new(SelectedDevice {
+            inner: backend,
+            id: id.to_string(),
+        }),
+        None => backend,
+    }
+}
+
+pub fn pull(backend: &dyn DeviceBackend, working_dir: &str, overwrite: bool) -> Result<String, Rc500Error> {
+    let devs = backend.list_devices()?;
+    let dev = pick_device(&devs)?;
+    let to = config_file_paths(working_dir);
+    if !overwrite && (to.0.exists() || to.1.exists()) {
+        return Err(Rc500Error::io(format!(
+            "{:?} already contains a config. Use --overwrite to replace it.",
+            working_dir
+        )));
+    }
+    do_pull(backend, &dev, working_dir)
+}
+
+pub fn push(backend: &dyn DeviceBackend, working_dir: &str) -> Result<String, Rc500Error> {
+    let issues = validate::check_dir(working_dir);
+    if !issues.is_empty() {
+        return Err(Rc500Error::invalid_value(format!(
+            "Not pushing, because the RC500 would reject the config:\n{}",
+            validate::format_issues(&issues)
+        )));
+    }
+    let devs = backend.list_devices()?;
+    let dev = pick_device(&devs)?;
+    do_push(backend, &dev, working_dir)
+}
+
+/// Carry out the plan on the memories in the working directory and push them, moving the recorded audio in the
+/// WAVE folder of the device along with them. The audio is only moved once the memories are on the device. If
+/// saving or pushing fails, the audio stays where it was and the local memory files are put back.
+pub fn push_rearranged(
+    backend: &dyn DeviceBackend,
+    working_dir: &str,
+    config: &mut model::Config,
+    plan: &slots::Plan,
+) -> Result<String, Rc500Error> {
+    let devs = backend.list_devices()?;
+    let dev = pick_device(&devs)?;
+    let waves = backend.wave_dir(&dev)?;
+    let paths = config_file_paths(working_dir);
+    let mut local: Vec<(&PathBuf, Vec<u8>)> = Vec::new();
+    for path in [&paths.0, &paths.1] {
+        local.push((
+            path,
+            fs::read(path).map_err(|e| Rc500Error::io(format!("Could not read {:?}: {}", path, e)))?,
+        ));
+    }
+    slots::apply(config, plan, Some(&waves))?;
+    let pushed = crate::save(config).and_then(|()| push(backend, working_dir));
+    match pushed {
+        Ok(msg) => {
+            slots::commit_audio(plan, &waves).map_err(|e| {
+                Rc500Error::io(format!(
+                    "{}. The memories were pushed, but their audio could not be moved: {}",
+                    msg,
+                    e.message()
+                ))
+            })?;
+            Ok(msg)
+        }
+        Err(e) => {
+            let _ = slots::discard_audio(&waves);
+            for (path, content) in &local {
+                fs::write(path, content)
+                    .map_err(|e| Rc500Error::io(format!("Could not put back {:?}: {}", path, e)))?;
+            }
+            Err(e.context("Left the audio on the device as it was and put back the local memory files"))
+        }
+    }
+}
+
+/// Push only the memories with the given ids: the device's memories are read, the selected ones are replaced
+/// by those in the working directory, and the result is written to the device as a new generation. Gives the
+/// changes to the device, and with `dry_run` nothing is written and the problems that would stop the push are
+/// listed along with them.
+pub fn push_only(
+    backend: &dyn DeviceBackend,
+    working_dir: &str,
+    ids: &[usize],
+    dry_run: bool,
+) -> Result<String, Rc500Error> {
+    let local = crate::load(working_dir)?;
+    let devs = backend.list_devices()?;
+    let dev = pick_device(&devs)?;
+    let (device, active) = read_device_memories(backend, &dev)?;
+    let mut merged = device.clone();
+    for id in ids {
+        let memory =
+            local.memories.iter().find(|memory| memory.id == *id).ok_or_else(|| {
+                Rc500Error::invalid_value(format!("There is no memory {} in {:?}", id + 1, working_dir))
+            })?;
+        match merged.memories.iter_mut().find(|memory| memory.id == *id) {
+            Some(target) => *target = memory.clone(),
+            None => {
+                return Err(Rc500Error::invalid_value(format!(
+                    "There is no memory {} on the device",
+                    id + 1
+                )))
+            }
         }
     }
-    for dev in &result {
-        println!("{}: {}", dev.name, dev.path)
+    let changes: Vec<String> = diff::diff(&device, &merged)
+        .iter()
+        .map(|change| change.to_string())
+        .collect();
+    let summary = match changes.is_empty() {
+        true => "No differences".to_string(),
+        false => changes.join("\n"),
+    };
+    let issues = validate::check(&merged);
+    let rejected = format!(
+        "The RC500 would reject the config:\n{}",
+        validate::format_issues(&issues)
+    );
+    if dry_run {
+        return Ok(match issues.is_empty() {
+            true => format!("{}\nDry run, nothing was pushed", summary),
+            false => format!("{}\n{}\nDry run, nothing was pushed", summary, rejected),
+        });
+    }
+    if !issues.is_empty() {
+        return Err(Rc500Error::invalid_value(format!("Not pushing. {}", rejected)));
+    }
+    // Like saving locally: the inactive file becomes the newest generation, the other one gets the same content
+    let newest = model::Config {
+        trailer: device.trailer.next(),
+        ..merged.clone()
+    };
+    let files = vec![
+        (MEMORY_FILES[active.other().index()], writer::render(&newest)),
+        (MEMORY_FILES[active.index()], writer::render(&merged)),
+    ];
+    let msg = safe_write(backend, &dev, working_dir, &files)?;
+    Ok(format!("{}\n{}", summary, msg))
+}
+
+/// Read the active memory file of the device, and which one it is.
+fn read_device_memories(
+    backend: &dyn DeviceBackend,
+    dev: &Device,
+) -> Result<(model::Config, buffers::Slot), Rc500Error> {
+    let read = |name: &str| -> Result<model::Config, Rc500Error> {
+        let content = backend.read_file(dev, name)?;
+        reader::parse(&content, &format!("{}:{}", dev.path, name))
+    };
+    let config1 = read(MEMORY_FILES[0])?;
+    let config2 = read(MEMORY_FILES[1])?;
+    match buffers::active_slot(&config1.trailer, &config2.trailer) {
+        buffers::Slot::A => Ok((config1, buffers::Slot::A)),
+        buffers::Slot::B => Ok((config2, buffers::Slot::B)),
+    }
+}
+
+/// Read the memories the device is using, from the active one of its memory files.
+pub fn read_device_config(backend: &dyn DeviceBackend) -> Result<model::Config, Rc500Error> {
+    let devs = backend.list_devices()?;
+    let dev = pick_device(&devs)?;
+    read_device_memories(backend, &dev).map(|(config, _)| config)
+}
+
+/// Describe the RC500s with their id and which memory file they use.
+pub fn describe_devices(backend: &dyn DeviceBackend) -> Result<String, Rc500Error> {
+    let devs = rc500s(backend)?;
+    if devs.is_empty() {
+        return Err(Rc500Error::no_device("No RC500 found"));
     }
-    Ok(())
+    let lines: Vec<String> = devs
+        .iter()
+        .map(|dev| {
+            let bank = match read_device_memories(backend, dev) {
+                Ok((config, slot)) => format!(
+                    "active bank {} (generation {})",
+                    MEMORY_FILES[slot.index()],
+                    config.trailer.generation
+                ),
+                Err(e) => format!("unreadable: {}", e),
+            };
+            format!("{}  {}  {}", dev.id, dev.path, bank)
+        })
+        .collect();
+    Ok(lines.join("\n"))
 }
 
-fn list_devices() -> WindowsResult<Vec<Device>> {
+/// Copy the memory and system files of one RC500 to another, in the same way as a push. The files that are
+/// replaced are archived in the working directory.
+pub fn sync(backend: &dyn DeviceBackend, from: &str, to: &str, working_dir: &str) -> Result<String, Rc500Error> {
+    let devs = rc500s(backend)?;
+    let find = |id: &str| {
+        devs.iter()
+            .find(|dev| dev.id == id)
+            .ok_or_else(|| Rc500Error::no_device(format!("No RC500 with id {:?}. Found: {}", id, device_ids(&devs))))
+    };
+    let (source, target) = (find(from)?, find(to)?);
+    if source.id == target.id {
+        return Err(Rc500Error::invalid_value("Cannot sync a device to itself"));
+    }
+    let (config, _) = read_device_memories(backend, source)?;
+    let issues = validate::check(&config);
+    if !issues.is_empty() {
+        return Err(Rc500Error::invalid_value(format!(
+            "Not syncing, because the RC500 would reject the config of {}:\n{}",
+            source.id,
+            validate::format_issues(&issues)
+        )));
+    }
+    let mut files: Vec<(&str, Vec<u8>)> = Vec::new();
+    for name in MEMORY_FILES.into_iter().chain(SYSTEM_FILES) {
+        if backend.has_file(source, name) {
+            files.push((name, backend.read_file(source, name)?));
+        }
+    }
+    let msg = format!(
+        "Copying the memory and system files from {} to {}",
+        source.id, target.id
+    );
+    safe_write(backend, target, working_dir, &files)
+        .map(|result| format!("{}. {}", msg, result))
+        .map_err(|e| e.context(&msg))
+}
+
+#[derive(Clone, Debug)]
+pub struct Device {
+    /// Identifies the device while it is connected: the device id on Windows, the mount path elsewhere.
+    pub id: String,
+    pub name: String,
+    pub path: String,
+}
+
+/// The devices of the backend that are an RC500.
+pub fn rc500s(backend: &dyn DeviceBackend) -> Result<Vec<Device>, Rc500Error> {
+    let devs = backend.list_devices()?;
+    Ok(devs.into_iter().filter(|dev| dev.name == DEVICE_NAME).collect())
+}
+
+fn device_ids<'a>(devs: impl IntoIterator<Item = &'a Device>) -> String {
+    let ids: Vec<&str> = devs.into_iter().map(|dev| dev.id.as_str()).collect();
+    ids.join(", ")
+}
+
+fn pick_device(devs: &[Device]) -> Result<Device, Rc500Error> {
+    let rc500s: Vec<&Device> = devs.iter().filter(|dev| dev.name == DEVICE_NAME).collect();
+    match rc500s[..] {
+        [] => Err(Rc500Error::no_device("No RC500 found")),
+        [dev] => Ok(dev.clone()),
+        _ => Err(Rc500Error::no_device(format!(
+            "Found {} RC500 devices. Choose one with --device: {}",
+            rc500s.len(),
+            device_ids(rc500s)
+        ))),
+    }
+}
+
+#[cfg(windows)]
+fn list_devices() -> Result<Vec<Device>, Rc500Error> {
+    list_portable_devices().map_err(|e| Rc500Error::no_device(format!("Could not retrieve any device info: {:?}", e)))
+}
+
+#[cfg(windows)]
+fn list_portable_devices() -> WindowsResult<Vec<Device>> {
     let fetch_infos = DeviceInformation::FindAllAsyncDeviceClass(DeviceClass::PortableStorageDevice)?;
     let infos = fetch_infos.get()?;
     let mut result: Vec<Device> = Vec::new();
@@ -75,50 +429,246 @@ fn list_devices() -> WindowsResult<Vec<Device>> {
     Ok(result)
 }
 
+#[cfg(windows)]
 fn scan_device(info: DeviceInformation) -> WindowsResult<Device> {
     let name = info.Name()?;
     let id = info.Id()?;
-    let storage_device = StorageDevice::FromId(id)?;
+    let storage_device = StorageDevice::FromId(id.clone())?;
     let path = storage_device.Path()?;
     Ok(Device {
+        id: id.to_string_lossy(),
         name: name.to_string_lossy(),
         path: path.to_string_lossy(),
     })
 }
 
-fn do_pull(device_root: &str, working_dir: &str) -> Result<String, String> {
-    let from = device_paths(device_root);
+#[cfg(not(windows))]
+fn list_devices() -> Result<Vec<Device>, Rc500Error> {
+    Ok(mounted_volumes()
+        .iter()
+        .filter_map(|volume| scan_volume(volume))
+        .collect())
+}
+
+/// The RC500 presents itself as a USB mass storage device, so any mounted volume that carries
+/// the memory files is considered to be one.
+#[cfg(not(windows))]
+fn scan_volume(volume: &Path) -> Option<Device> {
+    if data_dir(volume).join(MEMORY_FILES[0]).is_file() {
+        Some(Device {
+            id: volume.to_string_lossy().to_string(),
+            name: DEVICE_NAME.to_string(),
+            path: volume.to_string_lossy().to_string(),
+        })
+    } else {
+        None
+    }
+}
+
+/// Collect the mount points that could belong to the RC500, without duplicates.
+#[cfg(not(windows))]
+fn mounted_volumes() -> Vec<PathBuf> {
+    let mut volumes: Vec<PathBuf> = Vec::new();
+    if let Ok(mounts) = fs::read_to_string("/proc/mounts") {
+        volumes.extend(mounts.lines().filter_map(parse_mount_point));
+    }
+    // Automounters put volumes in /media/<user>/<label>, /run/media/<user>/<label> or /Volumes/<label>.
+    for base in ["/media", "/run/media"] {
+        for dir in subdirectories(Path::new(base)) {
+            volumes.push(dir.clone());
+            volumes.extend(subdirectories(&dir));
+        }
+    }
+    volumes.extend(subdirectories(Path::new("/Volumes")));
+    let mut result: Vec<PathBuf> = Vec::new();
+    for volume in volumes {
+        if !result.contains(&volume) {
+            result.push(volume);
+        }
+    }
+    result
+}
+
+/// Extract the mount point from a line of /proc/mounts. Whitespace in paths is octal escaped.
+#[cfg(not(windows))]
+fn parse_mount_point(line: &str) -> Option<PathBuf> {
+    let escaped = line.split_whitespace().nth(1)?;
+    let mut path = String::new();
+    let mut rest = escaped;
+    while let Some(i) = rest.find('\\') {
+        path.push_str(&rest[..i]);
+        match rest
+            .get(i + 1..i + 4)
+            .and_then(|octal| u8::from_str_radix(octal, 8).ok())
+        {
+            Some(byte) => {
+                path.push(byte as char);
+                rest = &rest[i + 4..];
+            }
+            None => {
+                path.push('\\');
+                rest = &rest[i + 1..];
+            }
+        }
+    }
+    path.push_str(rest);
+    Some(PathBuf::from(path))
+}
+
+#[cfg(not(windows))]
+fn subdirectories(dir: &Path) -> Vec<PathBuf> {
+    match fs::read_dir(dir) {
+        Err(_) => Vec::new(),
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.is_dir())
+            .collect(),
+    }
+}
+
+fn do_pull(backend: &dyn DeviceBackend, dev: &Device, working_dir: &str) -> Result<String, Rc500Error> {
     let to = config_file_paths(working_dir);
-    let msg = format!("Copying {:?} to {:?}", from, to);
-    match fs::copy(from.0, to.0).and_then(|_| fs::copy(from.1, to.1)) {
-        Err(e) => Err(format!("{}. Error occurred while trying to copy data: {:?}", msg, e)),
-        Ok(_v) => Ok(format!("{}. Successfully pulled data", msg)),
+    let system = system_file_paths(working_dir);
+    let msg = format!("Copying memory and system files from {} to {:?}", dev.path, to);
+    let copy = || -> Result<(), Rc500Error> {
+        for (name, path) in MEMORY_FILES.iter().zip([&to.0, &to.1]) {
+            let content = backend.read_file(dev, name)?;
+            fs::write(path, content).map_err(|e| Rc500Error::io(format!("Could not write {:?}: {}", path, e)))?;
+        }
+        for (name, path) in SYSTEM_FILES.iter().zip([&system.0, &system.1]) {
+            if backend.has_file(dev, name) {
+                let content = backend.read_file(dev, name)?;
+                fs::write(path, content).map_err(|e| Rc500Error::io(format!("Could not write {:?}: {}", path, e)))?;
+            }
+        }
+        Ok(())
+    };
+    match copy() {
+        Err(e) => Err(e.context(&format!("{}. Error occurred while trying to copy data", msg))),
+        Ok(()) => Ok(format!("{}. Successfully pulled data", msg)),
     }
 }
 
-fn do_push(device_root: &str, working_dir: &str) -> Result<String, String> {
+/// Push the memory and system files in the working directory, see [`safe_write`].
+fn do_push(backend: &dyn DeviceBackend, dev: &Device, working_dir: &str) -> Result<String, Rc500Error> {
     let from = config_file_paths(working_dir);
-    let to = device_paths(device_root);
-    let msg = format!("Copying {:?} to {:?}", from, to);
-    match fs::copy(from.0, to.0).and_then(|_| fs::copy(from.1, to.1)) {
-        Err(e) => Err(format!("{}. Error occurred while trying to copy data: {:?}", msg, e)),
-        Ok(_v) => Ok(format!("{}. Successfully pushed data", msg)),
+    let system = system_file_paths(working_dir);
+    let msg = format!("Copying {:?} to {}", from, dev.path);
+    let mut files: Vec<(&str, Vec<u8>)> = Vec::new();
+    for (name, path) in MEMORY_FILES.iter().zip([&from.0, &from.1]) {
+        files.push((
+            name,
+            fs::read(path).map_err(|e| Rc500Error::io(format!("Could not read {:?}: {}", path, e)))?,
+        ));
+    }
+    // Directories pulled by older versions have no system files
+    for (name, path) in SYSTEM_FILES.iter().zip([&system.0, &system.1]) {
+        if path.is_file() {
+            files.push((
+                name,
+                fs::read(path).map_err(|e| Rc500Error::io(format!("Could not read {:?}: {}", path, e)))?,
+            ));
+        }
+    }
+    safe_write(backend, dev, working_dir, &files)
+        .map(|result| format!("{}. {}", msg, result))
+        .map_err(|e| e.context(&msg))
+}
+
+/// Write the files to the device. The files on the device are archived locally first, every written file is
+/// read back and compared with what was written, and on any failure the archived files are put back and the
+/// files that were not on the device before are removed.
+fn safe_write(
+    backend: &dyn DeviceBackend,
+    dev: &Device,
+    working_dir: &str,
+    files: &[(&str, Vec<u8>)],
+) -> Result<String, Rc500Error> {
+    let mut previous: Vec<(&str, Vec<u8>)> = Vec::new();
+    for (name, _) in files {
+        if backend.has_file(dev, name) {
+            previous.push((name, backend.read_file(dev, name)?));
+        }
+    }
+    let archive = backup::archive_device(working_dir, &previous)?;
+
+    let mut written: Vec<&str> = Vec::new();
+    let mut copy = || -> Result<(), Rc500Error> {
+        for (name, content) in files {
+            written.push(name);
+            backend.write_file(dev, name, content)?;
+            verify(backend, dev, name, content)?;
+        }
+        Ok(())
+    };
+    match copy() {
+        Ok(()) => Ok(format!(
+            "Successfully pushed data. The previous files of the device are in {:?}",
+            archive
+        )),
+        Err(e) => {
+            let rollback = previous
+                .iter()
+                .try_for_each(|(name, content)| backend.write_file(dev, name, content))
+                .and_then(|()| {
+                    written
+                        .iter()
+                        .filter(|name| !previous.iter().any(|(previous, _)| previous == *name))
+                        .filter(|name| backend.has_file(dev, name))
+                        .try_for_each(|name| backend.remove_file(dev, name))
+                });
+            match rollback {
+                Ok(()) => Err(Rc500Error::io(format!(
+                    "Error occurred while trying to copy data: {}. Put back the previous files of the device",
+                    e
+                ))),
+                Err(rollback_error) => Err(Rc500Error::io(format!(
+                    "Error occurred while trying to copy data: {}. Could not put back the previous files of the \
+                     device either: {}. Copy them from {:?} to the device by hand",
+                    e, rollback_error, archive
+                ))),
+            }
+        }
     }
 }
 
-fn device_paths(device_root: &str) -> (PathBuf, PathBuf) {
+/// Check that the file on the device has the same settings as what was written to it.
+fn verify(backend: &dyn DeviceBackend, dev: &Device, name: &str, expected: &[u8]) -> Result<(), Rc500Error> {
+    let content = backend.read_file(dev, name)?;
+    let device_name = format!("{}:{}", dev.path, name);
+    let same = if SYSTEM_FILES.contains(&name) {
+        let expected = reader::parse_system(expected, name)?;
+        let written = reader::parse_system(&content, &device_name)?;
+        expected.menus == written.menus && expected.trailer == written.trailer
+    } else {
+        let expected = reader::parse(expected, name)?;
+        let written = reader::parse(&content, &device_name)?;
+        expected.memories == written.memories && expected.trailer == written.trailer
+    };
+    match same {
+        true => Ok(()),
+        false => Err(Rc500Error::io(format!("{} differs from what was written", device_name))),
+    }
+}
+
+pub const MEMORY_FILES: [&str; 2] = ["MEMORY1.RC0", "MEMORY2.RC0"];
+pub const SYSTEM_FILES: [&str; 2] = ["SYSTEM1.RC0", "SYSTEM2.RC0"];
+
+fn data_dir(device_root: &Path) -> PathBuf {
+    device_root.join("ROLAND").join("DATA")
+}
+
+pub fn config_file_paths(working_dir: &str) -> (PathBuf, PathBuf) {
     (
-        PathBuf::new()
-            .join(device_root)
-            .join(Path::new(r"ROLAND\DATA\MEMORY1.RC0")),
-        PathBuf::new()
-            .join(device_root)
-            .join(Path::new(r"ROLAND\DATA\MEMORY2.RC0")),
+        PathBuf::new().join(working_dir).join(Path::new(MEMORY_FILES[0])),
+        PathBuf::new().join(working_dir).join(Path::new(MEMORY_FILES[1])),
     )
 }
-pub fn config_file_paths(working_dir: &str) -> (PathBuf, PathBuf) {
+
+pub fn system_file_paths(working_dir: &str) -> (PathBuf, PathBuf) {
     (
-        PathBuf::new().join(working_dir).join(Path::new(r"MEMORY1.RC0")),
-        PathBuf::new().join(working_dir).join(Path::new(r"MEMORY2.RC0")),
+        PathBuf::new().join(working_dir).join(Path::new(SYSTEM_FILES[0])),
+        PathBuf::new().join(working_dir).join(Path::new(SYSTEM_FILES[1])),
     )
 }
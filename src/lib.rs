@@ -0,0 +1,233 @@
+//! Reading, editing, validating and writing the configuration files of the Boss RC-500 loop station.
+//!
+//! The RC500 stores its memories in two copies, `MEMORY1.RC0` and `MEMORY2.RC0`. Each is an XML document
+//! followed by a few bytes of binary data, see [`trailer`] and [`buffers`]. [`load`] reads the newest copy from a directory, after which the
+//! settings can be inspected and changed through [`model`] (by menu and key name) or [`typed`] (as Rust
+//! structs). [`schema`] describes what every setting means and which values it accepts.
+//!
+//! The global settings of the device are stored the same way, in `SYSTEM1.RC0` and `SYSTEM2.RC0`. They are
+//! read with [`load_system`] and edited through [`model`] only.
+//!
+//! ```no_run
+//! let mut config = boss_rc500::load(".")?;
+//! boss_rc500::set_setting(&mut config, 0, "TRACK1", "PlyLvl", 150)?;
+//! boss_rc500::save(&mut config)?;
+//! # Ok::<(), String>(())
+//! ```
+
+use std::path::Path;
+
+pub mod backup;
+pub mod buffers;
+pub mod diff;
+pub mod error;
+pub mod export;
+pub mod git;
+pub mod io;
+pub mod lint;
+pub mod model;
+pub mod path;
+pub mod reader;
+pub mod repair;
+pub mod schema;
+pub mod setlist;
+pub mod slots;
+pub mod trailer;
+pub mod typed;
+pub mod validate;
+pub mod writer;
+
+pub use error::Rc500Error;
+pub use model::{Config, SystemConfig};
+pub use path::SettingPath;
+
+/// Load the newest of the two memory files in the given directory.
+pub fn load(dir: &str) -> Result<Config, Rc500Error> {
+    let (path1, path2) = io::config_file_paths(dir);
+    let config1 = reader::read(&path1)?;
+    let config2 = reader::read(&path2)?;
+    match buffers::active_slot(&config1.trailer, &config2.trailer) {
+        buffers::Slot::A => Ok(config1),
+        buffers::Slot::B => Ok(config2),
+    }
+}
+
+/// Load the newest of the two system files in the given directory. Gives `None` if there are none, as in
+/// directories pulled by older versions.
+pub fn load_system(dir: &str) -> Result<Option<SystemConfig>, Rc500Error> {
+    let (path1, path2) = io::system_file_paths(dir);
+    match (path1.exists(), path2.exists()) {
+        (false, false) => Ok(None),
+        (true, false) => reader::read_system(&path1).map(Some),
+        (false, true) => reader::read_system(&path2).map(Some),
+        (true, true) => {
+            let system1 = reader::read_system(&path1)?;
+            let system2 = reader::read_system(&path2)?;
+            match buffers::active_slot(&system1.trailer, &system2.trailer) {
+                buffers::Slot::A => Ok(Some(system1)),
+                buffers::Slot::B => Ok(Some(system2)),
+            }
+        }
+    }
+}
+
+/// Load the newest memory file if the path is a directory, or else the memory file at the path.
+pub fn load_path(path: &str) -> Result<Config, Rc500Error> {
+    if Path::new(path).is_dir() {
+        load(path)
+    } else {
+        load_file(Path::new(path))
+    }
+}
+
+/// Write both memory files with a complete bank of memories with default settings, see [`schema`] for where
+/// they come from. Existing memory files are only replaced with `overwrite`. The system files are not written, because their defaults are not known.
+pub fn init(dir: &str, overwrite: bool) -> Result<Config, Rc500Error> {
+    let (path1, path2) = io::config_file_paths(dir);
+    if !overwrite && (path1.exists() || path2.exists()) {
+        return Err(Rc500Error::io(format!(
+            "{:?} already contains a config. Use --overwrite to replace it.",
+            dir
+        )));
+    }
+    let config = slots::default_config(&path1.to_string_lossy());
+    for path in [&path1, &path2] {
+        writer::write(&path.to_string_lossy(), &config)?;
+    }
+    Ok(config)
+}
+
+/// Load a single memory file.
+pub fn load_file(path: &Path) -> Result<Config, Rc500Error> {
+    reader::read(path)
+}
+
+/// Check that every value is within the bounds of its parameter.
+pub fn validate(config: &Config) -> Result<(), Rc500Error> {
+    schema::check_config(config)
+}
+
+/// Write the config as the newest generation. If it was loaded from one of the memory files, both memory
+/// files are written, see [`buffers`].
+pub fn save(config: &mut Config) -> Result<(), Rc500Error> {
+    validate(config)?;
+    let (filename, trailer) = buffers::save(&config.filename, io::MEMORY_FILES, &config.trailer, |trailer| {
+        let mut config = config.clone();
+        config.trailer = trailer.clone();
+        writer::render(&config)
+    })?;
+    config.filename = filename;
+    config.trailer = trailer;
+    Ok(())
+}
+
+/// Write the system settings as the newest generation, to both system files like [`save`].
+pub fn save_system(system: &mut SystemConfig) -> Result<(), Rc500Error> {
+    let (filename, trailer) = buffers::save(&system.filename, io::SYSTEM_FILES, &system.trailer, |trailer| {
+        let mut system = system.clone();
+        system.trailer = trailer.clone();
+        writer::render_system(&system)
+    })?;
+    system.filename = filename;
+    system.trailer = trailer;
+    Ok(())
+}
+
+/// Get the value of a setting of the memory with the given id, which counts from 0.
+pub fn get_setting(config: &Config, memory: usize, menu: &str, key: &str) -> Option<usize> {
+    model::get_setting(find_memory(config, memory).ok()?, menu, key)
+}
+
+/// Get the value at the path. Labels and units are included unless `raw` is set, in which case the number
+/// stored in the file is given.
+pub fn get_value(config: &Config, path: &SettingPath, raw: bool) -> Result<String, Rc500Error> {
+    let memory = find_memory(config, path.memory)?;
+    let key = match &path.key {
+        None => return Ok(model::get_memory_name(memory).to_string()),
+        Some(key) => key,
+    };
+    let value = model::get_setting(memory, &path.menu, key)
+        .ok_or_else(|| Rc500Error::invalid_value(format!("There is no setting {}", path)))?;
+    match schema::param(&path.menu, key) {
+        Some(param) if !raw => Ok(param.display_value(value)),
+        _ => Ok(value.to_string()),
+    }
+}
+
+/// Set the value at the path, given as label or as number. For a NAME, the text is the new name.
+pub fn set_value(config: &mut Config, path: &SettingPath, text: &str) -> Result<(), Rc500Error> {
+    let key = match &path.key {
+        None => return set_name(config, path.memory, text),
+        Some(key) => key,
+    };
+    let value = match schema::param(&path.menu, key) {
+        Some(param) => param
+            .parse_value(text)
+            .map_err(|e| Rc500Error::invalid_value(format!("{}: {}", path, e.message())))?,
+        None => text
+            .trim()
+            .parse::<usize>()
+            .map_err(|_| Rc500Error::invalid_value(format!("{}: '{}' is not a number", path, text)))?,
+    };
+    set_setting(config, path.memory, &path.menu, key, value)
+}
+
+/// Rename the memory with the given id. The name must fit on the display of the RC500.
+pub fn set_name(config: &mut Config, memory: usize, name: &str) -> Result<(), Rc500Error> {
+    if name.chars().count() > model::STRING_MAX_WIDTH {
+        return Err(Rc500Error::invalid_value(format!(
+            "'{}' is longer than {} characters",
+            name,
+            model::STRING_MAX_WIDTH
+        )));
+    }
+    if let Some(c) = name.chars().find(|c| !model::is_name_char(*c)) {
+        return Err(Rc500Error::invalid_value(format!(
+            "The RC500 cannot show the character '{}'",
+            c
+        )));
+    }
+    let memory = find_memory_mut(config, memory)?;
+    match memory
+        .menus
+        .iter_mut()
+        .find(|menu| menu.name == "NAME")
+        .map(|menu| &mut menu.content)
+    {
+        Some(model::MenuContent::StringValueMenu(menu)) => {
+            menu.value = name.trim_end().to_string();
+            Ok(())
+        }
+        _ => Err(Rc500Error::invalid_value(format!(
+            "Memory {} has no NAME",
+            memory.id + 1
+        ))),
+    }
+}
+
+fn find_memory(config: &Config, memory: usize) -> Result<&model::Memory, Rc500Error> {
+    config
+        .memories
+        .iter()
+        .find(|m| m.id == memory)
+        .ok_or_else(|| Rc500Error::invalid_value(format!("There is no memory {}", memory + 1)))
+}
+
+fn find_memory_mut(config: &mut Config, memory: usize) -> Result<&mut model::Memory, Rc500Error> {
+    config
+        .memories
+        .iter_mut()
+        .find(|m| m.id == memory)
+        .ok_or_else(|| Rc500Error::invalid_value(format!("There is no memory {}", memory + 1)))
+}
+
+/// Change the value of a setting of the memory with the given id, which counts from 0.
+/// Values outside of the bounds of the parameter are refused.
+pub fn set_setting(config: &mut Config, memory: usize, menu: &str, key: &str, value: usize) -> Result<(), Rc500Error> {
+    schema::check_value(menu, key, value)?;
+    let memory = find_memory_mut(config, memory)?;
+    let setting = model::get_setting_mut(memory, menu, key)
+        .ok_or_else(|| Rc500Error::invalid_value(format!("There is no setting {}/{}", menu, key)))?;
+    setting.value = value;
+    Ok(())
+}
@@ -0,0 +1,342 @@
+//! Settings that the RC500 accepts, but that are probably not what was meant on stage.
+//!
+//! Every finding comes from a [`Rule`] with an id and a [`Severity`]. A rule can be silenced for some memories
+//! in `.rc500/lint.toml` in the working directory, by memory number or range:
+//!
+//! ```toml
+//! [allow]
+//! 12 = ["assign-same-source"]
+//! "7-9" = ["target-min-above-max", "loop-sync-without-clock"]
+//! ```
+
+use crate::error::Rc500Error;
+use crate::model;
+use crate::schema;
+use crate::slots;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    /// Often done on purpose
+    Info,
+    Warning,
+    /// The memory will not work as intended
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Severity::Info => write!(f, "info"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+pub struct Rule {
+    pub id: &'static str,
+    pub severity: Severity,
+    pub description: &'static str,
+}
+
+pub const ASSIGN_SAME_SOURCE: Rule = Rule {
+    id: "assign-same-source",
+    severity: Severity::Warning,
+    description: "Two enabled assigns are driven by the same source but control different targets",
+};
+
+pub const CTL_AND_ASSIGN: Rule = Rule {
+    id: "ctl-and-assign",
+    severity: Severity::Warning,
+    description: "A pedal or footswitch has a function in CTL and also drives an enabled assign",
+};
+
+pub const AUTO_REC_WITHOUT_SENSITIVITY: Rule = Rule {
+    id: "auto-rec-without-sensitivity",
+    severity: Severity::Error,
+    description: "Auto Record is on with a sensitivity of 0, so recording never starts",
+};
+
+pub const LOOP_SYNC_WITHOUT_CLOCK: Rule = Rule {
+    id: "loop-sync-without-clock",
+    severity: Severity::Info,
+    description: "Loop Sync is on while the rhythm is silent and the memory does not sync, so there is nothing \
+                  to align the tracks to but the first recording",
+};
+
+pub const TARGET_MIN_ABOVE_MAX: Rule = Rule {
+    id: "target-min-above-max",
+    severity: Severity::Info,
+    description: "The Target Min of an enabled assign is above its Target Max, so the target moves the other way",
+};
+
+pub const RULES: [&Rule; 5] = [
+    &ASSIGN_SAME_SOURCE,
+    &CTL_AND_ASSIGN,
+    &AUTO_REC_WITHOUT_SENSITIVITY,
+    &LOOP_SYNC_WITHOUT_CLOCK,
+    &TARGET_MIN_ABOVE_MAX,
+];
+
+/// The CTL settings that give a switch a function, with the ASSIGN source value of the same switch.
+const CTL_SOURCES: [(&str, usize); 6] = [
+    ("Pedal1", 0),
+    ("Pedal2", 1),
+    ("Pedal3", 2),
+    ("Exp", 3),
+    ("Ctl1", 4),
+    ("Ctl2", 5),
+];
+
+/// A rule that a memory breaks.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Finding {
+    pub rule: &'static str,
+    pub severity: Severity,
+    /// The id of the memory, which counts from 0
+    pub memory: usize,
+    /// The settings involved, like TRACK1/LoopSync
+    pub settings: Vec<String>,
+    pub message: String,
+}
+
+impl Finding {
+    fn new(rule: &Rule, memory: &model::Memory, settings: &[(&str, &str)], message: String) -> Finding {
+        Finding {
+            rule: rule.id,
+            severity: rule.severity,
+            memory: memory.id,
+            settings: settings.iter().map(|(menu, key)| format!("{}/{}", menu, key)).collect(),
+            message,
+        }
+    }
+
+    /// Whether the setting is one of those involved.
+    pub fn concerns(&self, menu: &str, key: &str) -> bool {
+        self.settings
+            .iter()
+            .any(|setting| setting.split_once('/') == Some((menu, key)))
+    }
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}[{}] Memory {}: {}",
+            self.severity,
+            self.rule,
+            self.memory + 1,
+            self.message
+        )
+    }
+}
+
+/// The rules that are silenced, by memory id.
+#[derive(Debug, Default)]
+pub struct Allow {
+    rules: HashMap<usize, Vec<String>>,
+}
+
+impl Allow {
+    pub fn allows(&self, memory: usize, rule: &str) -> bool {
+        self.rules
+            .get(&memory)
+            .is_some_and(|rules| rules.iter().any(|allowed| allowed == rule))
+    }
+}
+
+#[derive(Deserialize)]
+struct LintFile {
+    #[serde(default)]
+    allow: IndexMap<String, Vec<String>>,
+}
+
+pub fn allow_file(working_dir: &str) -> PathBuf {
+    Path::new(working_dir).join(".rc500").join("lint.toml")
+}
+
+/// Read the silenced rules of the working directory. Without a lint file, nothing is silenced.
+pub fn load_allow(working_dir: &str) -> Result<Allow, Rc500Error> {
+    let path = allow_file(working_dir);
+    let text = match std::fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Allow::default()),
+        Err(e) => return Err(Rc500Error::io(format!("Could not read {:?}: {}", path, e))),
+    };
+    parse_allow(&text).map_err(|e| match e {
+        Rc500Error::Parse { message, .. } => Rc500Error::parse(&path.to_string_lossy(), message),
+        e => e.context(&format!("{:?}", path)),
+    })
+}
+
+pub fn parse_allow(text: &str) -> Result<Allow, Rc500Error> {
+    let file: LintFile = toml::from_str(text).map_err(|e| Rc500Error::parse("lint file", e.to_string()))?;
+    let mut allow = Allow::default();
+    for (memories, rules) in file.allow {
+        if let Some(rule) = rules.iter().find(|rule| !RULES.iter().any(|known| known.id == *rule)) {
+            return Err(Rc500Error::invalid_value(format!("There is no lint rule '{}'", rule)));
+        }
+        for memory in slots::parse_list(&memories)? {
+            allow.rules.entry(memory).or_default().extend(rules.iter().cloned());
+        }
+    }
+    Ok(allow)
+}
+
+/// The findings for every memory, in order of memory.
+pub fn lint(config: &model::Config, allow: &Allow) -> Vec<Finding> {
+    config
+        .memories
+        .iter()
+        .flat_map(|memory| lint_memory(memory, allow))
+        .collect()
+}
+
+/// The findings for a single memory, leaving out the rules that are silenced for it.
+pub fn lint_memory(memory: &model::Memory, allow: &Allow) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    check_assigns(memory, &mut findings);
+    check_auto_rec(memory, &mut findings);
+    check_loop_sync(memory, &mut findings);
+    findings.retain(|finding| !allow.allows(memory.id, finding.rule));
+    findings
+}
+
+fn label(menu: &str, key: &str, value: usize) -> String {
+    match schema::param(menu, key) {
+        Some(param) => param.display_value(value),
+        None => value.to_string(),
+    }
+}
+
+/// An enabled ASSIGN menu with the settings the rules look at.
+struct Assign<'a> {
+    menu: &'a str,
+    source: usize,
+    target: usize,
+}
+
+fn enabled_assigns(memory: &model::Memory) -> Vec<Assign<'_>> {
+    memory
+        .menus
+        .iter()
+        .filter(|menu| menu.name.starts_with("ASSIGN"))
+        .filter(|menu| model::get_setting(memory, &menu.name, "Sw") == Some(1))
+        .filter_map(|menu| {
+            Some(Assign {
+                menu: &menu.name,
+                source: model::get_setting(memory, &menu.name, "Source")?,
+                target: model::get_setting(memory, &menu.name, "Target")?,
+            })
+        })
+        .collect()
+}
+
+fn check_assigns(memory: &model::Memory, findings: &mut Vec<Finding>) {
+    let assigns = enabled_assigns(memory);
+    for (i, a) in assigns.iter().enumerate() {
+        for b in assigns[i + 1..]
+            .iter()
+            .filter(|b| b.source == a.source && b.target != a.target)
+        {
+            findings.push(Finding::new(
+                &ASSIGN_SAME_SOURCE,
+                memory,
+                &[
+                    (a.menu, "Source"),
+                    (a.menu, "Target"),
+                    (b.menu, "Source"),
+                    (b.menu, "Target"),
+                ],
+                format!(
+                    "{} and {} are both driven by {}, with the targets {} and {}",
+                    a.menu,
+                    b.menu,
+                    label("ASSIGN", "Source", a.source),
+                    label("ASSIGN", "Target", a.target),
+                    label("ASSIGN", "Target", b.target)
+                ),
+            ));
+        }
+    }
+    for (key, source) in CTL_SOURCES {
+        let function = match model::get_setting(memory, "CTL", key) {
+            Some(function) if function != 0 => function,
+            _ => continue,
+        };
+        for assign in assigns.iter().filter(|assign| assign.source == source) {
+            findings.push(Finding::new(
+                &CTL_AND_ASSIGN,
+                memory,
+                &[("CTL", key), (assign.menu, "Source")],
+                format!(
+                    "{} is set to {} in CTL and also drives {} ({})",
+                    label("ASSIGN", "Source", source),
+                    label("CTL", key, function),
+                    assign.menu,
+                    label("ASSIGN", "Target", assign.target)
+                ),
+            ));
+        }
+    }
+    for assign in &assigns {
+        let min = model::get_setting(memory, assign.menu, "TargetMin");
+        let max = model::get_setting(memory, assign.menu, "TargetMax");
+        if let (Some(min), Some(max)) = (min, max) {
+            if min > max {
+                findings.push(Finding::new(
+                    &TARGET_MIN_ABOVE_MAX,
+                    memory,
+                    &[(assign.menu, "TargetMin"), (assign.menu, "TargetMax")],
+                    format!("{} has Target Min {} above Target Max {}", assign.menu, min, max),
+                ));
+            }
+        }
+    }
+}
+
+fn check_auto_rec(memory: &model::Memory, findings: &mut Vec<Finding>) {
+    let auto_rec = model::get_setting(memory, "MASTER", "AutoRec");
+    let sensitivity = model::get_setting(memory, "MASTER", "AutoRecSens");
+    if auto_rec == Some(1) && sensitivity == Some(0) {
+        findings.push(Finding::new(
+            &AUTO_REC_WITHOUT_SENSITIVITY,
+            memory,
+            &[("MASTER", "AutoRec"), ("MASTER", "AutoRecSens")],
+            "Auto Record is on, but its sensitivity is 0".to_string(),
+        ));
+    }
+}
+
+/// The rhythm is silent when its level is 0 or all of its parts are off. The parameter guide does not say what
+/// the values of MASTER/Sync mean; fresh memories have 0 and do not follow a MIDI clock, so 0 is taken as off.
+/// Because that is not certain, the finding is only informational.
+fn check_loop_sync(memory: &model::Memory, findings: &mut Vec<Finding>) {
+    let setting = |menu: &str, key: &str| model::get_setting(memory, menu, key);
+    let parts = ["Part1", "Part2", "Part3", "Part4"];
+    let silent_rhythm =
+        setting("RHYTHM", "Level") == Some(0) || parts.iter().all(|part| setting("RHYTHM", part) == Some(0));
+    if !silent_rhythm || setting("MASTER", "Sync") != Some(0) {
+        return;
+    }
+    for track in ["TRACK1", "TRACK2"] {
+        if setting(track, "LoopSync") == Some(1) {
+            findings.push(Finding::new(
+                &LOOP_SYNC_WITHOUT_CLOCK,
+                memory,
+                &[(track, "LoopSync"), ("RHYTHM", "Level"), ("MASTER", "Sync")],
+                format!(
+                    "{} has Loop Sync on, but the rhythm is silent and MIDI sync is off",
+                    track
+                ),
+            ));
+        }
+    }
+}
@@ -1,12 +1,12 @@
+use boss_rc500::export::{self, Format};
+use boss_rc500::{
+    backup, buffers, diff, git, io, lint, model, repair, setlist, slots, validate, writer, Rc500Error, SettingPath,
+};
 use clap::Parser;
+use std::path::{Path, PathBuf};
 mod arith;
-mod descriptions;
 mod editor;
 mod exit_codes;
-mod io;
-mod model;
-mod reader;
-mod writer;
 
 /// Simple program to greet a person
 #[derive(Parser, Debug)]
@@ -15,32 +15,257 @@ struct Args {
     /// Optionally specify a different working directory than the current one
     #[clap(short, long, default_value("."))]
     working_dir: String,
+    /// Use a directory laid out like the RC500 (containing ROLAND/DATA/) instead of a connected device
+    #[clap(long)]
+    device_root: Option<String>,
+    /// The id of the RC500 to use when several are connected, as shown by `rc500 devices`
+    #[clap(long)]
+    device: Option<String>,
+    /// Do not take snapshots in .rc500/backups/ when pulling, pushing or saving
+    #[clap(long)]
+    no_backup: bool,
+    /// Print errors, and the output of diff, as JSON
+    #[clap(long, global(true))]
+    json: bool,
     #[clap(subcommand)]
     command: Command,
 }
 
 #[derive(clap::Subcommand, Debug)]
 enum Command {
-    /// Pull the configuration settings from a connected rc500 to the given directory
+    /// Write memory files for all 99 memories without a device, with the defaults of the parameter guide.
+    /// Settings the guide gives no default for take the value of a memory pulled from an RC500, so the
+    /// memories are not necessarily the same as after a factory reset.
+    Init {
+        /// If the working directory already contains a config file, overwrite it
+        #[clap(short, long)]
+        overwrite: bool,
+    },
+    /// Pull the memories and system settings from a connected rc500 to the given directory
     Pull {
         /// If the working directory already contains a config file, overwrite it
         #[clap(short, long)]
         overwrite: bool,
     },
     /// Push the configuration settings in the given directory to a connected rc500.
-    /// If the configuration settings are somehow invalid, the RC500 will use a backup configuration file
-    /// that we do not touch. You will notice this by the fact that your changes are not applied.
-    Push {},
+    /// Both memory files are written, and the RC500 uses the one with the newest generation. If that one is
+    /// somehow invalid, the RC500 falls back to the other one, so your changes are not applied.
+    Push {
+        /// Only push these memories, like 5,7-9, keeping the other memories on the device as they are
+        #[clap(long)]
+        only: Option<String>,
+        /// Only show what would change on the device
+        #[clap(long)]
+        dry_run: bool,
+    },
+    /// List the connected RC500s with their id and the memory file they use
+    Devices {},
+    /// Copy the memory and system files from one connected RC500 to another
+    Sync {
+        /// The id of the RC500 to copy from
+        from: String,
+        /// The id of the RC500 to copy to. Its files are archived in the working directory first.
+        to: String,
+    },
+    /// Check that the configuration settings in the given directory would be accepted by the RC500.
+    /// This check also runs before every push.
+    Validate {},
+    /// Point out settings that the RC500 accepts but that are probably mistakes, like a pedal that is used in
+    /// CTL and by an assign. Rules can be silenced per memory in .rc500/lint.toml, like `[allow] 12 = ["rule"]`.
+    /// Fails if a rule with severity error is broken.
+    Lint {
+        /// List the rules with their id and severity instead
+        #[clap(long)]
+        rules: bool,
+    },
+    /// Salvage what can be read from damaged memory files, like those of an interrupted pull. Missing settings
+    /// get their default, values out of range are clamped and the binary data at the end is rebuilt.
+    /// Without a file, the memory files in the working directory are repaired where needed.
+    Repair {
+        /// The memory file to repair
+        file: Option<String>,
+        /// Write the repaired file here instead of replacing the file
+        #[clap(short, long, requires("file"))]
+        output: Option<String>,
+    },
+    /// Print the value of a setting, addressed like 12/TRACK1/PlyLvl or 12/NAME
+    Get {
+        path: SettingPath,
+        /// Print the number stored in the file instead of its label
+        #[clap(long)]
+        raw: bool,
+    },
+    /// Change the value of a setting, addressed like 12/TRACK1/PlyLvl or 12/NAME.
+    /// The value can be given as number or as label, like "Loop End".
+    Set { path: SettingPath, value: String },
+    /// Write the memories as JSON, YAML or TOML, with display names and labels
+    Export {
+        #[clap(long, default_value("yaml"))]
+        format: Format,
+        /// Write to this file instead of printing
+        #[clap(short, long)]
+        output: Option<String>,
+    },
+    /// Apply the settings in a file written by export to the memories in the given directory
+    Import {
+        file: String,
+        /// The format of the file. By default the extension of the file decides.
+        #[clap(long)]
+        format: Option<Format>,
+    },
+    /// Show the settings that differ between two memory files or directories,
+    /// or with --device between the device and the working directory
+    Diff {
+        /// The old config: a memory file, or a directory to take the newest memory file from
+        a: Option<String>,
+        /// The new config
+        b: Option<String>,
+        /// Compare what is on the device with the working directory
+        #[clap(long, conflicts_with_all(&["a", "b"]))]
+        device: bool,
+    },
+    /// Print the settings of a memory file as text, for use as git textconv driver
+    GitTextconv { file: String },
+    /// Merge the changes from BASE to THEIRS into OURS per setting, for use as git merge driver
+    /// with `rc500 git-merge %O %A %B`. Fails if a setting was changed on both sides.
+    GitMerge { base: String, ours: String, theirs: String },
+    /// Copy, swap, move, clear or rename memories
+    Memory {
+        #[clap(subcommand)]
+        command: MemoryCommand,
+        /// Also push the memories, and move their recorded audio in the WAVE folder of the device along with them
+        #[clap(long)]
+        push: bool,
+    },
+    /// Put the memories in the order of a setlist
+    Setlist {
+        #[clap(subcommand)]
+        command: SetlistCommand,
+    },
+    /// List or take snapshots of the memory and system files
+    Backup {
+        #[clap(subcommand)]
+        command: BackupCommand,
+    },
+    /// Put back the memory and system files of a snapshot, after taking a snapshot of the current ones
+    Restore {
+        /// The id of the snapshot, as shown by `rc500 backup list`
+        id: String,
+    },
+    /// Show which copy of the memory and system files the RC500 will use, and why
+    Status {},
     /// Interactively edit the configuration settings
     Edit {},
 }
 
+/// Memories are given by their number, counting from 1.
+#[derive(clap::Subcommand, Debug)]
+enum MemoryCommand {
+    /// Copy the settings of a memory to another memory or a range like 10-15, keeping their names
+    Copy { from: String, to: String },
+    /// Swap two memories
+    Swap { a: String, b: String },
+    /// Move a memory to another slot and shift the memories in between
+    Move { from: String, to: String },
+    /// Reset a memory, or one of its menus like TRACK1, to the default settings
+    Clear { memory: String, menu: Option<String> },
+    /// Change the name of a memory
+    Rename { memory: String, name: String },
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum SetlistCommand {
+    /// Move the songs of the setlist, a TOML file like `songs = ["INTRO", 12]`, to memory 1, 2, ...
+    Apply {
+        file: String,
+        /// Only show which memories would move
+        #[clap(long)]
+        dry_run: bool,
+        /// Also push the memories, and move their recorded audio in the WAVE folder of the device along with them
+        #[clap(long, conflicts_with("dry-run"))]
+        push: bool,
+    },
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum BackupCommand {
+    /// Show the snapshots and the settings that changed in each of them
+    List {},
+    /// Take a snapshot of the current files
+    Create {},
+}
+
+/// Run an operation that changes the local or device files, with snapshots unless --no-backup was given.
+fn backed_up<T, E: From<Rc500Error>>(
+    working_dir: &str,
+    no_backup: bool,
+    reason: &str,
+    operation: impl FnOnce() -> Result<T, E>,
+) -> Result<T, E> {
+    match no_backup {
+        true => operation(),
+        false => backup::around(working_dir, reason, operation),
+    }
+}
+
+/// The snapshots with the settings that changed since the one before, newest last.
+fn list_backups(working_dir: &str) -> Result<String, Rc500Error> {
+    const MAX_CHANGES: usize = 10;
+    let snapshots = backup::list(working_dir)?;
+    if snapshots.is_empty() {
+        return Ok("No snapshots yet".to_string());
+    }
+    let mut lines = Vec::new();
+    for (i, snapshot) in snapshots.iter().enumerate() {
+        let previous = i.checked_sub(1).map(|i| &snapshots[i]);
+        match backup::changes(previous, snapshot) {
+            Err(e) => lines.push(format!("{}  {}: {}", snapshot.id, snapshot.reason, e)),
+            Ok(_) if previous.is_none() => lines.push(format!("{}  {}: first snapshot", snapshot.id, snapshot.reason)),
+            Ok(changes) => {
+                let plural = if changes.len() == 1 { "" } else { "s" };
+                lines.push(format!(
+                    "{}  {}: {} change{}",
+                    snapshot.id,
+                    snapshot.reason,
+                    changes.len(),
+                    plural
+                ));
+                lines.extend(changes.iter().take(MAX_CHANGES).map(|change| format!("    {}", change)));
+                if changes.len() > MAX_CHANGES {
+                    lines.push(format!("    and {} more", changes.len() - MAX_CHANGES));
+                }
+            }
+        }
+    }
+    Ok(lines.join("\n"))
+}
+
+/// What was repaired in each file, or that it was fine.
+fn repair_report(repaired: &[(PathBuf, PathBuf, Vec<repair::Fix>)]) -> String {
+    let mut lines = Vec::new();
+    for (file, output, fixes) in repaired {
+        if fixes.is_empty() {
+            lines.push(format!("{:?}: nothing to repair", file));
+            continue;
+        }
+        let plural = if fixes.len() == 1 { "" } else { "es" };
+        lines.push(format!("{:?}: {} fix{}, wrote {:?}", file, fixes.len(), plural, output));
+        lines.extend(fixes.iter().map(|fix| format!("    {}", fix)));
+    }
+    lines.join("\n")
+}
+
 fn main() {
     let args = Args::parse();
+    let json = args.json;
     match run(args) {
         Err(e) => {
-            println!("{}", e);
-            std::process::exit(exit_codes::ERROR)
+            let code = exit_codes::for_error(&e);
+            match json {
+                true => println!("{}", error_json(&e, code)),
+                false => println!("{}", e),
+            }
+            std::process::exit(code)
         }
         Ok(msg) => {
             println!("{}", msg);
@@ -49,34 +274,323 @@ fn main() {
     };
 }
 
-fn run(args: Args) -> Result<String, String> {
+/// The error as JSON object with its kind, message, exit code and any details like the file.
+fn error_json(error: &Rc500Error, code: exit_codes::ExitCode) -> String {
+    let mut value = serde_json::to_value(error).unwrap_or_default();
+    if let serde_json::Value::Object(object) = &mut value {
+        object.insert("exit_code".to_string(), code.into());
+    }
+    value.to_string()
+}
+
+fn run(args: Args) -> Result<String, Rc500Error> {
+    let backend = io::backend(args.device_root.as_deref(), args.device.as_deref());
     match args.command {
+        Command::Init { overwrite } => {
+            backed_up(&args.working_dir, args.no_backup, "init", || {
+                boss_rc500::init(&args.working_dir, overwrite)
+            })?;
+            Ok(format!(
+                "Wrote memory files with default settings to {:?}",
+                args.working_dir
+            ))
+        }
         Command::Pull { overwrite } => {
-            io::print_devices()?;
-            io::pull(&args.working_dir, overwrite)
+            backed_up(&args.working_dir, args.no_backup, "pull", || {
+                io::pull(backend.as_ref(), &args.working_dir, overwrite)
+            })
+        }
+        Command::Push { only, dry_run } => {
+            let ids = match (only, dry_run) {
+                (Some(only), _) => slots::parse_list(&only)?,
+                (None, true) => (0..model::NR_MEMORIES).collect(),
+                (None, false) => {
+                    return backed_up(&args.working_dir, args.no_backup, "push", || {
+                        io::push(backend.as_ref(), &args.working_dir)
+                    })
+                }
+            };
+            match dry_run {
+                true => io::push_only(backend.as_ref(), &args.working_dir, &ids, true),
+                false => backed_up(&args.working_dir, args.no_backup, "push", || {
+                    io::push_only(backend.as_ref(), &args.working_dir, &ids, false)
+                }),
+            }
+        }
+        Command::Devices {} => {
+            let backend = io::backend(args.device_root.as_deref(), None);
+            io::describe_devices(backend.as_ref())
+        }
+        Command::Sync { from, to } => {
+            let backend = io::backend(args.device_root.as_deref(), None);
+            io::sync(backend.as_ref(), &from, &to, &args.working_dir)
+        }
+        Command::Validate {} => {
+            let issues = validate::check_dir(&args.working_dir);
+            if issues.is_empty() {
+                Ok("No problems found".to_string())
+            } else {
+                Err(Rc500Error::invalid_value(validate::format_issues(&issues)))
+            }
+        }
+        Command::Lint { rules: true } => Ok(lint::RULES
+            .iter()
+            .map(|rule| format!("{} ({}): {}", rule.id, rule.severity, rule.description))
+            .collect::<Vec<String>>()
+            .join("\n")),
+        Command::Lint { rules: false } => {
+            let config = boss_rc500::load(&args.working_dir)?;
+            let findings = lint::lint(&config, &lint::load_allow(&args.working_dir)?);
+            let errors = findings
+                .iter()
+                .filter(|finding| finding.severity == lint::Severity::Error)
+                .count();
+            if args.json {
+                let report = serde_json::to_string_pretty(&findings).map_err(|e| e.to_string())?;
+                if errors == 0 {
+                    return Ok(report);
+                }
+                // The findings come first, followed by the error object like for any other error
+                println!("{}", report);
+                let plural = if errors == 1 { "" } else { "s" };
+                return Err(Rc500Error::invalid_value(format!(
+                    "Found {} problem{} with severity error",
+                    errors, plural
+                )));
+            }
+            if findings.is_empty() {
+                return Ok("No problems found".to_string());
+            }
+            let report = findings
+                .iter()
+                .map(|finding| finding.to_string())
+                .collect::<Vec<String>>()
+                .join("\n");
+            match errors {
+                0 => Ok(report),
+                _ => Err(Rc500Error::invalid_value(report)),
+            }
+        }
+        Command::Repair { file, output } => {
+            let targets: Vec<(PathBuf, PathBuf)> = match file {
+                Some(file) => vec![(PathBuf::from(&file), PathBuf::from(output.unwrap_or(file)))],
+                None => {
+                    let paths = io::config_file_paths(&args.working_dir);
+                    [paths.0, paths.1]
+                        .into_iter()
+                        .filter(|path| path.exists())
+                        .map(|path| (path.clone(), path))
+                        .collect()
+                }
+            };
+            if targets.is_empty() {
+                return Err(Rc500Error::io(format!(
+                    "There are no memory files in {:?}",
+                    args.working_dir
+                )));
+            }
+            let repaired = backed_up(&args.working_dir, args.no_backup, "repair", || {
+                let mut repaired = Vec::new();
+                for (file, output) in targets {
+                    let (config, fixes) = repair::repair_file(&file)?;
+                    if !fixes.is_empty() || file != output {
+                        writer::write(&output.to_string_lossy(), &config)?;
+                    }
+                    repaired.push((file, output, fixes));
+                }
+                Ok::<_, Rc500Error>(repaired)
+            })?;
+            if args.json {
+                let files: Vec<serde_json::Value> = repaired
+                    .iter()
+                    .map(|(file, output, fixes)| serde_json::json!({"file": file, "output": output, "fixes": fixes}))
+                    .collect();
+                Ok(serde_json::to_string_pretty(&files).map_err(|e| e.to_string())?)
+            } else {
+                Ok(repair_report(&repaired))
+            }
         }
-        Command::Push {} => {
-            io::print_devices()?;
-            io::push(&args.working_dir)
+        Command::Get { path, raw } => {
+            let config = boss_rc500::load(&args.working_dir)?;
+            boss_rc500::get_value(&config, &path, raw)
+        }
+        Command::Set { path, value } => {
+            let mut config = boss_rc500::load(&args.working_dir)?;
+            let old = boss_rc500::get_value(&config, &path, false)?;
+            boss_rc500::set_value(&mut config, &path, &value)?;
+            backed_up(&args.working_dir, args.no_backup, "set", || {
+                boss_rc500::save(&mut config)
+            })?;
+            let new = boss_rc500::get_value(&config, &path, false)?;
+            Ok(format!("{}: {} -> {}", path, old, new))
+        }
+        Command::Export { format, output } => {
+            let config = boss_rc500::load(&args.working_dir)?;
+            let text = export::to_string(&export::export(&config), format)?;
+            match output {
+                None => Ok(text),
+                Some(output) => {
+                    std::fs::write(&output, text)
+                        .map_err(|e| Rc500Error::io(format!("Could not write {:?}: {}", output, e)))?;
+                    Ok(format!("Exported the memories to {:?}", output))
+                }
+            }
+        }
+        Command::Import { file, format } => {
+            let format = format.or_else(|| Format::from_file_name(&file)).ok_or_else(|| {
+                Rc500Error::invalid_value(format!("Cannot tell the format of {:?}, use --format", file))
+            })?;
+            let text = std::fs::read_to_string(&file)
+                .map_err(|e| Rc500Error::io(format!("Could not read {:?}: {}", file, e)))?;
+            let mut config = boss_rc500::load(&args.working_dir)?;
+            let export = export::from_str(&text, format).map_err(|e| Rc500Error::parse(&file, e.message()))?;
+            export::import(&mut config, &export)?;
+            backed_up(&args.working_dir, args.no_backup, "import", || {
+                boss_rc500::save(&mut config)
+            })?;
+            Ok(format!("Imported {:?}", file))
+        }
+        Command::Diff { a, b, device } => {
+            let (old, new) = match (device, a, b) {
+                (true, _, _) => (
+                    io::read_device_config(backend.as_ref())?,
+                    boss_rc500::load(&args.working_dir)?,
+                ),
+                (false, Some(a), Some(b)) => (boss_rc500::load_path(&a)?, boss_rc500::load_path(&b)?),
+                _ => return Err(Rc500Error::invalid_value("Give two configs to compare, or --device")),
+            };
+            let changes = diff::diff(&old, &new);
+            if args.json {
+                Ok(serde_json::to_string_pretty(&changes).map_err(|e| e.to_string())?)
+            } else if changes.is_empty() {
+                Ok("No differences".to_string())
+            } else {
+                Ok(changes
+                    .iter()
+                    .map(|change| change.to_string())
+                    .collect::<Vec<String>>()
+                    .join("\n"))
+            }
+        }
+        Command::GitTextconv { file } => {
+            let config = boss_rc500::load_file(Path::new(&file))?;
+            Ok(git::textconv(&config))
+        }
+        Command::GitMerge { base, ours, theirs } => {
+            let load = |path: &str| boss_rc500::load_file(Path::new(path));
+            let (merged, conflicts) = git::merge(&load(&base)?, &load(&ours)?, &load(&theirs)?);
+            std::fs::write(&ours, writer::render(&merged))
+                .map_err(|e| Rc500Error::io(format!("Could not write {:?}: {}", ours, e)))?;
+            if conflicts.is_empty() {
+                Ok("Merged without conflicts".to_string())
+            } else {
+                let lines: Vec<String> = conflicts.iter().map(|conflict| conflict.to_string()).collect();
+                Err(Rc500Error::from(format!(
+                    "Conflicting changes, kept ours:\n{}",
+                    lines.join("\n")
+                )))
+            }
+        }
+        Command::Memory { command, push } => {
+            let mut config = boss_rc500::load(&args.working_dir)?;
+            let number = |text: &str| -> Result<usize, Rc500Error> {
+                let range = slots::parse_range(text)?;
+                match range.start() == range.end() {
+                    true => Ok(*range.start()),
+                    false => Err(Rc500Error::invalid_value(format!(
+                        "Expected a single memory, not {}",
+                        text
+                    ))),
+                }
+            };
+            let plan = match &command {
+                MemoryCommand::Copy { from, to } => slots::copy(number(from)?, slots::parse_range(to)?),
+                MemoryCommand::Swap { a, b } => slots::swap(number(a)?, number(b)?),
+                MemoryCommand::Move { from, to } => slots::move_to(number(from)?, number(to)?),
+                MemoryCommand::Clear { memory, menu: None } => slots::clear(number(memory)?),
+                MemoryCommand::Clear {
+                    memory,
+                    menu: Some(menu),
+                } => {
+                    slots::reset_menu(&mut config, number(memory)?, &menu.to_uppercase())?;
+                    Vec::new()
+                }
+                MemoryCommand::Rename { memory, name } => {
+                    boss_rc500::set_name(&mut config, number(memory)?, name)?;
+                    Vec::new()
+                }
+            };
+            if push {
+                return backed_up(&args.working_dir, args.no_backup, "memory", || {
+                    io::push_rearranged(backend.as_ref(), &args.working_dir, &mut config, &plan)
+                });
+            }
+            slots::apply(&mut config, &plan, None)?;
+            backed_up(&args.working_dir, args.no_backup, "memory", || {
+                boss_rc500::save(&mut config)
+            })?;
+            Ok("Updated the memories".to_string())
+        }
+        Command::Setlist {
+            command: SetlistCommand::Apply { file, dry_run, push },
+        } => {
+            let text = std::fs::read_to_string(&file)
+                .map_err(|e| Rc500Error::io(format!("Could not read {:?}: {}", file, e)))?;
+            let mut config = boss_rc500::load(&args.working_dir)?;
+            let songs = setlist::parse(&text).map_err(|e| Rc500Error::parse(&file, e.message()))?;
+            let order = setlist::order(&config, &songs)?;
+            let preview = setlist::preview(&config, &order);
+            if !dry_run {
+                let plan = setlist::plan(&config, &order);
+                if push {
+                    let msg = backed_up(&args.working_dir, args.no_backup, "setlist", || {
+                        io::push_rearranged(backend.as_ref(), &args.working_dir, &mut config, &plan)
+                    })?;
+                    return Ok(format!("{}\n{}", preview, msg));
+                }
+                slots::apply(&mut config, &plan, None)?;
+                backed_up(&args.working_dir, args.no_backup, "setlist", || {
+                    boss_rc500::save(&mut config)
+                })?;
+            }
+            Ok(preview)
+        }
+        Command::Backup {
+            command: BackupCommand::List {},
+        } => Ok(list_backups(&args.working_dir)?),
+        Command::Backup {
+            command: BackupCommand::Create {},
+        } => match backup::create(&args.working_dir, "manual")? {
+            Some(id) => Ok(format!("Took snapshot {}", id)),
+            None => Ok("Nothing changed since the last snapshot".to_string()),
+        },
+        Command::Restore { id } => {
+            backup::restore(&args.working_dir, &id)?;
+            Ok(format!("Restored snapshot {}", id))
+        }
+        Command::Status {} => {
+            let dir = Path::new(&args.working_dir);
+            let mut report = buffers::status(dir, io::MEMORY_FILES).to_string();
+            let system_paths = io::system_file_paths(&args.working_dir);
+            if system_paths.0.exists() || system_paths.1.exists() {
+                report = format!("{}\n\n{}", report, buffers::status(dir, io::SYSTEM_FILES));
+            }
+            Ok(report)
         }
         Command::Edit {} => {
-            let mut config = read(&args.working_dir)?;
-            match editor::editor(&mut config, &args.working_dir) {
-                Err(e) => Err(format!("{:?}", e)),
+            let mut config = boss_rc500::load(&args.working_dir)?;
+            let mut system = boss_rc500::load_system(&args.working_dir)?;
+            match editor::editor(
+                &mut config,
+                &mut system,
+                &args.working_dir,
+                args.device_root.as_deref(),
+                args.device.as_deref(),
+                !args.no_backup,
+            ) {
+                Err(e) => Err(e),
                 Ok(()) => Ok("Exiting editor".to_string()),
             }
         }
     }
 }
-
-fn read(working_dir: &str) -> Result<model::Config, String> {
-    // Only use the newest of the two
-    let (path1, path2) = io::config_file_paths(&working_dir);
-    let config1 = reader::read(&path1)?;
-    let config2 = reader::read(&path2)?;
-    if config1.suffix[0] < config2.suffix[0] {
-        Ok(config2)
-    } else {
-        Ok(config1)
-    }
-}
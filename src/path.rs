@@ -0,0 +1,51 @@
+//! Addressing a single setting from the command line.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// The location of a setting, written as `<memory>/<menu>/<key>` with memories counted from 1, for example
+/// `12/TRACK1/PlyLvl`. The name of a memory is addressed as `<memory>/NAME`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SettingPath {
+    /// The id of the memory, which counts from 0
+    pub memory: usize,
+    pub menu: String,
+    /// None for the NAME menu, which holds a single string
+    pub key: Option<String>,
+}
+
+impl FromStr for SettingPath {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = text.split('/').collect();
+        let (memory, menu, key) = match parts[..] {
+            [memory, "NAME"] => (memory, "NAME", None),
+            [memory, menu, key] if menu != "NAME" => (memory, menu, Some(key)),
+            _ => {
+                return Err(format!(
+                    "'{}' is not a setting. Expected <memory>/<menu>/<key>, like 12/TRACK1/PlyLvl, or <memory>/NAME",
+                    text
+                ))
+            }
+        };
+        let memory = match memory.parse::<usize>() {
+            Ok(memory) if memory >= 1 => memory - 1,
+            _ => return Err(format!("'{}' is not a memory number, which counts from 1", memory)),
+        };
+        Ok(SettingPath {
+            memory,
+            menu: menu.to_string(),
+            key: key.map(|key| key.to_string()),
+        })
+    }
+}
+
+impl fmt::Display for SettingPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.key {
+            Some(key) => write!(f, "{}/{}/{}", self.memory + 1, self.menu, key),
+            None => write!(f, "{}/{}", self.memory + 1, self.menu),
+        }
+    }
+}
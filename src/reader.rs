@@ -1,92 +1,234 @@
+use crate::error::{Location, Rc500Error};
 use crate::model;
-use roxmltree;
+use crate::trailer::Trailer;
 use std::path::Path;
 use std::str;
 
-fn find_subsequence(haystack: &Vec<u8>, needle: &[u8]) -> Option<usize> {
+fn find_subsequence(haystack: &[u8], needle: &[u8]) -> Option<usize> {
     haystack.windows(needle.len()).position(|window| window == needle)
 }
 
-pub fn read(filename: &Path) -> Result<model::Config, String> {
-    // NOTE: read binary because the suffix can contain null bytes
-    let text = &std::fs::read(filename).map_err(|e| format!("Reading error: {}.", e))?;
-    // The suffix characters are not valid xml
-    let n = find_subsequence(text, b"</database>\n").ok_or("Could not find datebase end tag")?;
-    let pivot = n + "</database>\n".len();
-    let xml_bytes = &text[0..pivot].to_vec();
-    let xml = str::from_utf8(xml_bytes).map_err(|_| "XML was not valid utf8")?;
-    let suffix = text[pivot..].to_vec();
+pub fn read(filename: &Path) -> Result<model::Config, Rc500Error> {
+    // NOTE: read binary because the trailer can contain null bytes
+    parse(&read_bytes(filename)?, &filename.to_string_lossy())
+}
+
+fn read_bytes(filename: &Path) -> Result<Vec<u8>, Rc500Error> {
+    std::fs::read(filename).map_err(|e| Rc500Error::io(format!("Could not read {:?}: {}", filename, e)))
+}
+
+/// Parse the content of a memory file. The filename is recorded in the config and in errors, which point
+/// at the line and column of the problem where possible.
+pub fn parse(text: &[u8], filename: &str) -> Result<model::Config, Rc500Error> {
+    let (xml, trailer) = split(text).map_err(|message| Rc500Error::parse(filename, message))?;
+    let doc = parse_xml(xml, filename)?;
+    let mut config = doc_to_config(&doc, filename, trailer).map_err(|e| e.locate(&doc, filename))?;
+    config.xml = Some(xml.to_string());
+    Ok(config)
+}
+
+pub fn read_system(filename: &Path) -> Result<model::SystemConfig, Rc500Error> {
+    parse_system(&read_bytes(filename)?, &filename.to_string_lossy())
+}
+
+/// Parse the content of a system file. The filename is recorded in the config and in errors.
+pub fn parse_system(text: &[u8], filename: &str) -> Result<model::SystemConfig, Rc500Error> {
+    let (xml, trailer) = split(text).map_err(|message| Rc500Error::parse(filename, message))?;
+    let doc = parse_xml(xml, filename)?;
+    let menus = doc_to_system_menus(&doc).map_err(|e| e.locate(&doc, filename))?;
+    Ok(model::SystemConfig {
+        filename: filename.to_string(),
+        xml: Some(xml.to_string()),
+        trailer,
+        menus,
+    })
+}
+
+fn parse_xml<'a>(xml: &'a str, filename: &str) -> Result<roxmltree::Document<'a>, Rc500Error> {
+    roxmltree::Document::parse(xml).map_err(|e| {
+        let pos = e.pos();
+        Rc500Error::Parse {
+            file: filename.to_string(),
+            message: format!("Parsing error: {}.", e),
+            location: Some(Location {
+                line: pos.row,
+                column: pos.col,
+                path: None,
+                snippet: snippet(xml, pos.row),
+            }),
+        }
+    })
+}
 
-    let v = roxmltree::Document::parse(&xml).map_err(|e| format!("Parsing error: {}.", e))?;
-    doc_to_config(v, &filename.to_string_lossy(), suffix)
+/// The line of the text, counting from 1.
+fn snippet(text: &str, line: u32) -> String {
+    text.lines()
+        .nth(line.saturating_sub(1) as usize)
+        .unwrap_or("")
+        .to_string()
 }
 
-fn validate_mem_node(node: roxmltree::Node) -> Result<(), String> {
+/// A problem with the content of the document, at the node it was found in.
+struct Diagnostic {
+    message: String,
+    /// The byte offset of the node in the XML
+    pos: Option<usize>,
+    /// The setting the problem is about, like 3/TRACK1/PlyLvl
+    path: Option<String>,
+}
+
+impl Diagnostic {
+    fn at(node: roxmltree::Node, message: String) -> Diagnostic {
+        Diagnostic {
+            message,
+            pos: Some(node.range().start),
+            path: None,
+        }
+    }
+
+    fn with_path(self, path: String) -> Diagnostic {
+        Diagnostic {
+            path: Some(path),
+            ..self
+        }
+    }
+
+    fn locate(self, doc: &roxmltree::Document, filename: &str) -> Rc500Error {
+        let location = self.pos.map(|pos| {
+            let text_pos = doc.text_pos_at(pos);
+            Location {
+                line: text_pos.row,
+                column: text_pos.col,
+                path: self.path,
+                snippet: snippet(doc.input_text(), text_pos.row),
+            }
+        });
+        Rc500Error::Parse {
+            file: filename.to_string(),
+            message: self.message,
+            location,
+        }
+    }
+}
+
+impl From<&str> for Diagnostic {
+    fn from(message: &str) -> Diagnostic {
+        Diagnostic {
+            message: message.to_string(),
+            pos: None,
+            path: None,
+        }
+    }
+}
+
+fn doc_to_system_menus(doc: &roxmltree::Document) -> Result<Vec<model::Menu>, Diagnostic> {
+    let database = doc.root().first_child().ok_or("Could not find toplevel element")?;
+    let sys_node = database
+        .children()
+        .find(|c| c.is_element())
+        .ok_or_else(|| Diagnostic::at(database, "Could not find the sys element".to_string()))?;
+    validate_node(sys_node, "sys")?;
+    read_menus(sys_node, "sys")
+}
+
+/// Read the XML and the trailer of a file, without interpreting the XML.
+pub fn read_parts(filename: &Path) -> Result<(String, Trailer), Rc500Error> {
+    let text = read_bytes(filename)?;
+    split(&text)
+        .map(|(xml, trailer)| (xml.to_string(), trailer))
+        .map_err(|message| Rc500Error::parse(&filename.to_string_lossy(), message))
+}
+
+/// Split the file in the XML and the trailer after it.
+fn split(text: &[u8]) -> Result<(&str, Trailer), String> {
+    // The trailer characters are not valid xml
+    let n = find_subsequence(text, b"</database>").ok_or("Could not find datebase end tag")?;
+    let mut pivot = n + "</database>".len();
+    for newline in [&b"\r\n"[..], &b"\n"[..]] {
+        if text[pivot..].starts_with(newline) {
+            pivot += newline.len();
+            break;
+        }
+    }
+    let xml = str::from_utf8(&text[0..pivot]).map_err(|_| "XML was not valid utf8")?;
+    let trailer = Trailer::parse(&text[pivot..])?;
+    Ok((xml, trailer))
+}
+
+fn validate_node(node: roxmltree::Node, expected: &str) -> Result<(), Diagnostic> {
     let tag = node.tag_name().name();
-    if tag != "mem" {
-        println!("{:?}", node);
-        Err(format!("Expected tag 'mem' but found '{}'", tag))
+    if tag != expected {
+        Err(Diagnostic::at(
+            node,
+            format!("Expected tag '{}' but found '{}'", expected, tag),
+        ))
     } else {
         Ok(())
     }
 }
 
-fn doc_to_config(doc: roxmltree::Document, filename: &str, suffix: Vec<u8>) -> Result<model::Config, String> {
+fn doc_to_config(doc: &roxmltree::Document, filename: &str, trailer: Trailer) -> Result<model::Config, Diagnostic> {
     let mut memories: Vec<model::Memory> = Vec::new();
-    let database = doc
-        .root()
-        .first_child()
-        .ok_or("Could not find toplevel element".to_string())?;
+    let database = doc.root().first_child().ok_or("Could not find toplevel element")?;
     for mem_node in database.children().filter(|c| c.is_element()) {
-        validate_mem_node(mem_node)?;
+        validate_node(mem_node, "mem")?;
         let id = mem_node
             .attribute("id")
-            .ok_or("Memory tag has no id attribute".to_string())
+            .ok_or_else(|| Diagnostic::at(mem_node, "Memory tag has no id attribute".to_string()))
             .and_then(|text| {
                 text.parse::<usize>()
-                    .map_err(|_e| format!("Id attribute is not an int, but: {}.", text))
+                    .map_err(|_e| Diagnostic::at(mem_node, format!("Id attribute is not an int, but: {}.", text)))
             })?;
-        let mut menus: Vec<model::Menu> = Vec::new();
-        for menu_node in mem_node.children().filter(|c| c.is_element()) {
-            let mut settings: Vec<model::UntypedKeyValue> = Vec::new();
-            for setting_node in menu_node.children().filter(|c| c.is_element()) {
-                let key = setting_node.tag_name().name().to_string();
-                let value = setting_node
-                    .text()
-                    .ok_or(format!("Setting {} has no value", key))
-                    .and_then(|text| {
-                        text.parse::<usize>()
-                            .map_err(|_e| format!("Setting value is not an int, but: {}.", text))
-                    })?;
-                let setting = model::UntypedKeyValue { key: key, value: value };
-                settings.push(setting);
-            }
-            let name = menu_node.tag_name().name().to_string();
-            let menu = if name == "NAME" {
-                read_string_menu(name, settings)
-            } else {
-                model::Menu {
-                    name: name,
-                    content: model::MenuContent::KeyValueMenu(model::UntypedMenu { settings: settings }),
-                }
-            };
-            menus.push(menu);
-        }
-        let memory = model::Memory { id: id, menus: menus };
+        let menus = read_menus(mem_node, &(id + 1).to_string())?;
+        let memory = model::Memory { id, menus };
         memories.push(memory);
     }
     Ok(model::Config {
         filename: filename.to_string(),
-        suffix: suffix,
-        memories: memories,
+        xml: None,
+        trailer,
+        memories,
     })
 }
 
+/// Read the menus of a memory or of the system settings. The parent path is used in diagnostics.
+fn read_menus(parent: roxmltree::Node, parent_path: &str) -> Result<Vec<model::Menu>, Diagnostic> {
+    let mut menus: Vec<model::Menu> = Vec::new();
+    for menu_node in parent.children().filter(|c| c.is_element()) {
+        let mut settings: Vec<model::UntypedKeyValue> = Vec::new();
+        let name = menu_node.tag_name().name().to_string();
+        for setting_node in menu_node.children().filter(|c| c.is_element()) {
+            let key = setting_node.tag_name().name().to_string();
+            let value = setting_node
+                .text()
+                .ok_or_else(|| Diagnostic::at(setting_node, format!("Setting {} has no value", key)))
+                .and_then(|text| {
+                    text.parse::<usize>().map_err(|_e| {
+                        Diagnostic::at(setting_node, format!("Setting value is not an int, but: {}.", text))
+                    })
+                })
+                .map_err(|e| e.with_path(format!("{}/{}/{}", parent_path, name, key)))?;
+            let setting = model::UntypedKeyValue { key, value };
+            settings.push(setting);
+        }
+        let menu = if name == "NAME" {
+            read_string_menu(name, settings)
+        } else {
+            model::Menu {
+                name,
+                content: model::MenuContent::KeyValueMenu(model::UntypedMenu { settings }),
+            }
+        };
+        menus.push(menu);
+    }
+    Ok(menus)
+}
+
 fn read_string_menu(name: String, settings: Vec<model::UntypedKeyValue>) -> model::Menu {
     let value = String::from_iter(settings.into_iter().map(|kv| (kv.value as u8) as char));
     let trimmed = value.trim_end().to_string();
     model::Menu {
-        name: name,
+        name,
         content: model::MenuContent::StringValueMenu(model::StringValueMenu { value: trimmed }),
     }
 }
@@ -0,0 +1,416 @@
+//! Salvaging memory files that cannot be read, like a file of which the pull was interrupted.
+//!
+//! The reader refuses a file as soon as anything is wrong with it. Repairing reads every `<mem>` element on
+//! its own instead, so a damaged memory only costs that memory. Whatever is missing or unreadable is filled in
+//! with the default of the setting, values outside of the bounds of their parameter are clamped, and a
+//! missing trailer is rebuilt. Every change is reported as a [`Fix`]. Menus and keys that are not known are kept as
+//! they are, so that repairing does not lose anything the RC500 might need.
+
+use crate::error::Rc500Error;
+use crate::model;
+use crate::schema;
+use crate::slots;
+use crate::trailer::{self, Trailer};
+use crate::typed;
+use serde::Serialize;
+use std::fmt;
+use std::path::Path;
+
+/// A change made to salvage a file, located by memory/menu/key like a [`crate::validate::Issue`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Fix {
+    pub path: String,
+    pub message: String,
+}
+
+impl fmt::Display for Fix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+fn fix(fixes: &mut Vec<Fix>, path: &str, message: String) {
+    fixes.push(Fix {
+        path: path.to_string(),
+        message,
+    });
+}
+
+const END_TAG: &str = "</database>";
+
+/// Read the memory file at the path, salvaging what can be read.
+pub fn repair_file(path: &Path) -> Result<(model::Config, Vec<Fix>), Rc500Error> {
+    let text = std::fs::read(path).map_err(|e| Rc500Error::io(format!("Could not read {:?}: {}", path, e)))?;
+    Ok(repair(&text, &path.to_string_lossy()))
+}
+
+/// Salvage the memories in the content of a memory file. The config has every known menu and key in the right
+/// order with values within bounds. A file that is fine gives no fixes, and is written back unchanged.
+///
+/// Memories missing in between are filled with defaults. A file that was cut off lost the memories after the
+/// cut, so it is filled up to a complete bank. A complete file with fewer memories keeps its number of memories.
+pub fn repair(text: &[u8], filename: &str) -> (model::Config, Vec<Fix>) {
+    let mut fixes = Vec::new();
+    let (xml, rest) = match text
+        .windows(END_TAG.len())
+        .position(|window| window == END_TAG.as_bytes())
+    {
+        Some(n) => {
+            let mut pivot = n + END_TAG.len();
+            for newline in [&b"\r\n"[..], &b"\n"[..]] {
+                if text[pivot..].starts_with(newline) {
+                    pivot += newline.len();
+                    break;
+                }
+            }
+            (&text[..pivot], Some(&text[pivot..]))
+        }
+        None => {
+            fix(
+                &mut fixes,
+                "database",
+                format!("The file ends before {}, it was cut off", END_TAG),
+            );
+            (text, None)
+        }
+    };
+    let xml = String::from_utf8_lossy(xml);
+    if let std::borrow::Cow::Owned(_) = xml {
+        fix(
+            &mut fixes,
+            "database",
+            "Replaced bytes that are not valid UTF-8".to_string(),
+        );
+    }
+    let trailer = repair_trailer(rest.unwrap_or_default(), &mut fixes);
+    let memories = repair_memories(&xml, rest.is_none(), &mut fixes);
+    // Writing patches the repaired values into the XML if it can still be read as a whole, so that what the
+    // model does not hold, like comments, is kept
+    let xml = Some(xml.into_owned()).filter(|xml| roxmltree::Document::parse(xml).is_ok());
+    let config = model::Config {
+        filename: filename.to_string(),
+        xml,
+        trailer,
+        memories,
+    };
+    (config, fixes)
+}
+
+/// Keep the trailer as it is if there is one: its bytes after the generation counter are not understood, so
+/// they are not touched. A missing trailer is rebuilt like the one of a new file.
+fn repair_trailer(bytes: &[u8], fixes: &mut Vec<Fix>) -> Trailer {
+    match Trailer::parse(bytes) {
+        Ok(trailer) => trailer,
+        Err(_) => {
+            fix(fixes, "trailer", "Missing, rebuilt as generation 0".to_string());
+            Trailer {
+                generation: 0,
+                rest: trailer::KNOWN_REST.to_vec(),
+            }
+        }
+    }
+}
+
+/// The memories taken from the `<mem>` elements that can be read, in order of id, see [`repair`] for which
+/// missing memories are filled in.
+fn repair_memories(xml: &str, cut_off: bool, fixes: &mut Vec<Fix>) -> Vec<model::Memory> {
+    let mut memories: Vec<Option<model::Memory>> = vec![None; model::NR_MEMORIES];
+    for (position, (start, chunk)) in mem_elements(xml).into_iter().enumerate() {
+        let line = xml[..start].matches('\n').count() + 1;
+        let doc = match roxmltree::Document::parse(chunk) {
+            Ok(doc) => doc,
+            Err(e) => {
+                fix(
+                    fixes,
+                    "database",
+                    format!("Dropped the memory on line {}, which cannot be read: {}", line, e),
+                );
+                continue;
+            }
+        };
+        let node = doc.root_element();
+        let id = match node.attribute("id").map(|id| id.trim().parse::<usize>()) {
+            Some(Ok(id)) => id,
+            _ => {
+                fix(
+                    fixes,
+                    &format!("Memory {}", position + 1),
+                    format!("The memory on line {} has no valid id, took its position", line),
+                );
+                position
+            }
+        };
+        match memories.get(id) {
+            None => fix(
+                fixes,
+                "database",
+                format!(
+                    "Dropped the memory on line {} with id {}, which is not a memory",
+                    line, id
+                ),
+            ),
+            Some(Some(_)) => fix(
+                fixes,
+                &format!("Memory {}", id + 1),
+                format!(
+                    "Dropped the memory on line {}, which has the same id as an earlier one",
+                    line
+                ),
+            ),
+            Some(None) => memories[id] = Some(repair_memory(node, id, fixes)),
+        }
+    }
+    if !cut_off {
+        let found = memories
+            .iter()
+            .rposition(|memory| memory.is_some())
+            .map_or(0, |last| last + 1);
+        memories.truncate(found);
+    }
+    memories
+        .into_iter()
+        .enumerate()
+        .map(|(id, memory)| {
+            memory.unwrap_or_else(|| {
+                fix(
+                    fixes,
+                    &format!("Memory {}", id + 1),
+                    "Missing, filled with defaults".to_string(),
+                );
+                slots::default_memory(id)
+            })
+        })
+        .collect()
+}
+
+/// The start and the text of every `<mem>` element. An element without end tag runs until the next one, or
+/// the end of the text, so that it fails to parse on its own.
+fn mem_elements(xml: &str) -> Vec<(usize, &str)> {
+    let starts: Vec<usize> = xml
+        .match_indices("<mem")
+        .map(|(start, _)| start)
+        .filter(|start| matches!(xml[start + 4..].chars().next(), Some(c) if c.is_whitespace() || c == '>' || c == '/'))
+        .collect();
+    starts
+        .iter()
+        .enumerate()
+        .map(|(i, &start)| {
+            let limit = starts.get(i + 1).copied().unwrap_or(xml.len());
+            let end = match xml[start..limit].find("</mem>") {
+                Some(n) => start + n + "</mem>".len(),
+                None => limit,
+            };
+            (start, &xml[start..end])
+        })
+        .collect()
+}
+
+/// The memory with every known menu, taking what can be read from the element and defaults for the rest.
+fn repair_memory(node: roxmltree::Node, id: usize, fixes: &mut Vec<Fix>) -> model::Memory {
+    let path = format!("Memory {}", id + 1);
+    let names = typed::menu_names();
+    let unknown: Vec<(Option<String>, model::Menu)> = unknown_elements(node, &names)
+        .into_iter()
+        .map(|(after, menu_node)| (after, unknown_menu(menu_node, &path, fixes)))
+        .collect();
+    let known = slots::default_memory(id)
+        .menus
+        .into_iter()
+        .map(|default| {
+            let menu_path = format!("{}/{}", path, default.name);
+            match node.children().find(|c| c.has_tag_name(default.name.as_str())) {
+                None => {
+                    fix(fixes, &menu_path, "Missing, filled with defaults".to_string());
+                    default
+                }
+                Some(menu_node) => repair_menu(menu_node, default, &menu_path, fixes),
+            }
+        })
+        .collect();
+    let menus = with_unknown(known, unknown, |menu: &model::Menu| menu.name.clone());
+    model::Memory { id, menus }
+}
+
+/// The elements among the children of the node that do not have one of the known names, each with the name
+/// of the known element before it.
+fn unknown_elements<'a, 'input>(
+    node: roxmltree::Node<'a, 'input>,
+    known: &[String],
+) -> Vec<(Option<String>, roxmltree::Node<'a, 'input>)> {
+    let mut after = None;
+    let mut unknown = Vec::new();
+    for child in node.children().filter(|c| c.is_element()) {
+        let name = child.tag_name().name();
+        match known.iter().any(|known| known == name) {
+            true => after = Some(name.to_string()),
+            false => unknown.push((after.clone(), child)),
+        }
+    }
+    unknown
+}
+
+/// The known items in their order, with the unknown items put back after the known item they followed.
+fn with_unknown<T>(known: Vec<T>, unknown: Vec<(Option<String>, T)>, name: impl Fn(&T) -> String) -> Vec<T> {
+    let mut unknown = unknown;
+    let mut items = Vec::new();
+    let mut put_back = |after: Option<String>, items: &mut Vec<T>| {
+        let (now, later): (Vec<_>, Vec<_>) = std::mem::take(&mut unknown)
+            .into_iter()
+            .partition(|(before, _)| *before == after);
+        unknown = later;
+        items.extend(now.into_iter().map(|(_, item)| item));
+    };
+    put_back(None, &mut items);
+    for item in known {
+        let after = Some(name(&item));
+        items.push(item);
+        put_back(after, &mut items);
+    }
+    items
+}
+
+/// A menu that is not known, with the settings that have a number as value.
+fn unknown_menu(node: roxmltree::Node, path: &str, fixes: &mut Vec<Fix>) -> model::Menu {
+    let name = node.tag_name().name().to_string();
+    let path = format!("{}/{}", path, name);
+    let settings = node
+        .children()
+        .filter(|c| c.is_element())
+        .filter_map(|setting_node| unknown_setting(setting_node, &path, fixes))
+        .collect();
+    model::Menu {
+        name,
+        content: model::MenuContent::KeyValueMenu(model::UntypedMenu { settings }),
+    }
+}
+
+/// A setting that is not known, if its value is a number.
+fn unknown_setting(node: roxmltree::Node, path: &str, fixes: &mut Vec<Fix>) -> Option<model::UntypedKeyValue> {
+    let key = node.tag_name().name().to_string();
+    let text = node.text().unwrap_or_default().trim();
+    match text.parse::<usize>() {
+        Ok(value) => Some(model::UntypedKeyValue { key, value }),
+        Err(_) => {
+            fix(
+                fixes,
+                &format!("{}/{}", path, key),
+                format!("Dropped the unknown key, its value '{}' is not a number", text),
+            );
+            None
+        }
+    }
+}
+
+fn repair_menu(node: roxmltree::Node, default: model::Menu, path: &str, fixes: &mut Vec<Fix>) -> model::Menu {
+    let name = default.name;
+    let content = match default.content {
+        model::MenuContent::StringValueMenu(default) => {
+            model::MenuContent::StringValueMenu(repair_name(node, default, path, fixes))
+        }
+        model::MenuContent::KeyValueMenu(default) => {
+            let params = schema::menu_params(&name);
+            let keys: Vec<String> = default.settings.iter().map(|setting| setting.key.clone()).collect();
+            let unknown: Vec<(Option<String>, model::UntypedKeyValue)> = unknown_elements(node, &keys)
+                .into_iter()
+                .filter_map(|(after, setting_node)| Some((after, unknown_setting(setting_node, path, fixes)?)))
+                .collect();
+            let known = default
+                .settings
+                .into_iter()
+                .map(|setting| {
+                    let param = params.and_then(|params| params.get(setting.key.as_str()));
+                    let value = repair_value(node, &setting, param, &format!("{}/{}", path, setting.key), fixes);
+                    model::UntypedKeyValue { value, ..setting }
+                })
+                .collect();
+            let settings = with_unknown(known, unknown, |setting: &model::UntypedKeyValue| setting.key.clone());
+            model::MenuContent::KeyValueMenu(model::UntypedMenu { settings })
+        }
+    };
+    model::Menu { name, content }
+}
+
+/// The value of the setting in the menu element, clamped to the bounds of its parameter, or else its default.
+fn repair_value(
+    menu: roxmltree::Node,
+    default: &model::UntypedKeyValue,
+    param: Option<&schema::Param>,
+    path: &str,
+    fixes: &mut Vec<Fix>,
+) -> usize {
+    let text = match menu.children().find(|c| c.has_tag_name(default.key.as_str())) {
+        None => {
+            fix(fixes, path, format!("Missing, set to the default {}", default.value));
+            return default.value;
+        }
+        Some(node) => node.text().unwrap_or_default().trim(),
+    };
+    let value = match text.parse::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            fix(
+                fixes,
+                path,
+                format!("'{}' is not a number, set to the default {}", text, default.value),
+            );
+            return default.value;
+        }
+    };
+    match param {
+        Some(param) if !param.is_in_bounds(value) => {
+            let clamped = value.clamp(param.min, param.max);
+            fix(
+                fixes,
+                path,
+                format!(
+                    "Value {} is outside of the range {}..={}, clamped to {}",
+                    value, param.min, param.max, clamped
+                ),
+            );
+            clamped
+        }
+        _ => value,
+    }
+}
+
+/// The name spelled by the character codes in the NAME element, without characters the RC500 cannot show.
+fn repair_name(
+    node: roxmltree::Node,
+    default: model::StringValueMenu,
+    path: &str,
+    fixes: &mut Vec<Fix>,
+) -> model::StringValueMenu {
+    let codes: Vec<Option<usize>> = node
+        .children()
+        .filter(|c| c.is_element())
+        .map(|c| c.text().and_then(|text| text.trim().parse().ok()))
+        .collect();
+    if codes.is_empty() {
+        fix(fixes, path, format!("Empty, set to the default '{}'", default.value));
+        return default;
+    }
+    let mut name = String::new();
+    for (i, code) in codes.iter().enumerate().take(model::STRING_MAX_WIDTH) {
+        match code.and_then(|code| u8::try_from(code).ok()).map(char::from) {
+            Some(c) if model::is_name_char(c) => name.push(c),
+            _ => {
+                fix(
+                    fixes,
+                    &format!("{}/C{:02}", path, i + 1),
+                    "Replaced a character the RC500 cannot show with a space".to_string(),
+                );
+                name.push(' ');
+            }
+        }
+    }
+    if codes.len() > model::STRING_MAX_WIDTH {
+        fix(
+            fixes,
+            path,
+            format!("Cut off after {} characters", model::STRING_MAX_WIDTH),
+        );
+    }
+    model::StringValueMenu {
+        value: name.trim_end().to_string(),
+    }
+}
@@ -0,0 +1,1444 @@
+//! The parameters of every menu of a memory. The system settings are not described here, because no real
+//! system file of an RC500 was at hand to take their keys and ranges from.
+//!
+//! Names, ranges and defaults follow the parameter guide in `docs/Boss_RC-500_Parameters.pdf`, where the
+//! default of a parameter is the value printed in bold. The guide gives no default for the loop length, the
+//! loop FX lengths, the assigns and the settings that are not shown on the RC500; those have the value found
+//! in the memories of `assets/small_config.xml`, unless noted otherwise.
+
+use phf::phf_ordered_map;
+
+use crate::error::Rc500Error;
+use crate::model;
+
+/// Marks a parameter of which the range has not been figured out yet, so that any value is accepted.
+const UNKNOWN: usize = usize::MAX;
+
+/// The parameters of a menu by tag name, in the order in which they appear in the file.
+pub type Params = phf::OrderedMap<&'static str, Param>;
+
+/// Everything there is to know about a single setting of a menu.
+pub struct Param {
+    /// Name shown to the user instead of the tag name
+    pub name: &'static str,
+    pub min: usize,
+    pub max: usize,
+    /// The value of a fresh memory, see the module documentation for where it comes from
+    pub default: usize,
+    /// Display names of the values, indexed by value
+    pub labels: Option<&'static [&'static str]>,
+    pub unit: Option<&'static str>,
+    pub description: &'static str,
+    /// Additional explanation of each value, indexed by value
+    pub value_descriptions: Option<&'static [&'static str]>,
+}
+
+impl Param {
+    pub fn display_value(&self, value: usize) -> String {
+        match (self.labels.and_then(|labels| labels.get(value)), self.unit) {
+            (Some(label), _) => label.to_string(),
+            (None, Some(unit)) => format!("{} ({})", value, unit),
+            (None, None) => format!("{}", value),
+        }
+    }
+
+    pub fn value_description(&self, value: usize) -> Option<&'static str> {
+        self.value_descriptions
+            .and_then(|descriptions| descriptions.get(value))
+            .copied()
+    }
+
+    pub fn is_in_bounds(&self, value: usize) -> bool {
+        self.min <= value && value <= self.max
+    }
+
+    /// Parse a value given as label (ignoring case) or as number, and check it against the bounds.
+    pub fn parse_value(&self, text: &str) -> Result<usize, Rc500Error> {
+        let text = text.trim();
+        let by_label = self
+            .labels
+            .and_then(|labels| labels.iter().position(|label| label.eq_ignore_ascii_case(text)));
+        let value = match (by_label, text.parse::<usize>()) {
+            (Some(value), _) | (None, Ok(value)) => value,
+            (None, Err(_)) => {
+                let expected = match self.labels {
+                    Some(labels) => format!("one of: {}", labels.join(", ")),
+                    None => "a number".to_string(),
+                };
+                return Err(Rc500Error::invalid_value(format!(
+                    "'{}' is not a value of {}, expected {}",
+                    text, self.name, expected
+                )));
+            }
+        };
+        if !self.is_in_bounds(value) {
+            return Err(Rc500Error::invalid_value(format!(
+                "Value {} of {} is outside of the range {}..={}",
+                value, self.name, self.min, self.max
+            )));
+        }
+        Ok(value)
+    }
+}
+
+/// The parameters of the menu with the given tag name, in the order in which they appear in the file.
+/// TRACK1 and TRACK2 share their parameters, and so do ASSIGN1 up to ASSIGN8.
+pub fn menu_params(menu: &str) -> Option<&'static Params> {
+    match menu {
+        "TRACK1" | "TRACK2" => Some(&TRACK),
+        "MASTER" => Some(&MASTER),
+        "LOOPFX" => Some(&LOOPFX),
+        "RHYTHM" => Some(&RHYTHM),
+        "CTL" => Some(&CTL),
+        _ if menu.starts_with("ASSIGN") => Some(&ASSIGN),
+        _ => None,
+    }
+}
+
+pub fn param(menu: &str, key: &str) -> Option<&'static Param> {
+    menu_params(menu).and_then(|params| params.get(key))
+}
+
+/// Check a value against the bounds of its parameter. Parameters that are not in the schema accept any value.
+pub fn check_value(menu: &str, key: &str, value: usize) -> Result<(), Rc500Error> {
+    match param(menu, key) {
+        Some(param) if !param.is_in_bounds(value) => Err(Rc500Error::invalid_value(format!(
+            "Value {} of {}/{} is outside of the range {}..={}",
+            value, menu, key, param.min, param.max
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Check every value in the config against the bounds of its parameter.
+pub fn check_config(config: &model::Config) -> Result<(), Rc500Error> {
+    for mem in &config.memories {
+        for menu in &mem.menus {
+            if let model::MenuContent::KeyValueMenu(settings) = &menu.content {
+                for setting in &settings.settings {
+                    check_value(&menu.name, &setting.key, setting.value)
+                        .map_err(|e| Rc500Error::invalid_value(format!("Memory {}: {}", mem.id + 1, e)))?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+pub static TRACK: phf::OrderedMap<&'static str, Param> = phf_ordered_map! {
+    "Rev" => Param {
+        name: "Reverse",
+        min: 0,
+        max: 1,
+        default: 0,
+        labels: Some(OFF_ON_LABELS),
+        unit: None,
+        description: "\
+Specifies conventional playback (Off) or reverse playback (On). When REVERSE is set to 'On' you won't be able to switch to overdubbing after a recording has been completed.
+",
+        value_descriptions: None,
+    },
+    "PlyLvl" => Param {
+        name: "Playback Level",
+        min: 0,
+        max: 200,
+        default: 100,
+        labels: None,
+        unit: None,
+        description: "Adjusts the playback level of the tracks.",
+        value_descriptions: None,
+    },
+    "Pan" => Param {
+        name: "Pan",
+        min: 0,
+        max: 100,
+        default: 50,
+        labels: None,
+        unit: None,
+        description: "Below 50 is left above 50 is right",
+        value_descriptions: None,
+    },
+    "One" => Param {
+        name: "1Shot",
+        min: 0,
+        max: 1,
+        default: 0,
+        labels: Some(OFF_ON_LABELS),
+        unit: None,
+        description: "\
+Specifies whether the track playback will be one-shot (On) or not one-shot (Off; conventional loop playback).
+
+Track 1: Off   |---||---||---||---|
+Track 2: On    |-----------|   <- 1SHOT is On, playback will stop when it reaches the end of the phrase.
+",
+        value_descriptions: None,
+    },
+    "LoopFx" => Param {
+        name: "Loop FX",
+        min: 0,
+        max: 1,
+        default: 1,
+        labels: Some(OFF_ON_LABELS),
+        unit: None,
+        description: "Specifies whether to apply LOOP FX (On) or not (Off) for this particular track.",
+        value_descriptions: None,
+    },
+    "StrtMod" => Param {
+        name: "Start",
+        min: 0,
+        max: 1,
+        default: 0,
+        labels: Some(&["Immediate", "Fade In"]),
+        unit: None,
+        description: "Specifies whether playback starts with a fade-in or immediately when the track plays. You can use 'FADE TIME' (p. 5) to specify the length of the fade-in.",
+        value_descriptions: None,
+    },
+    "StpMod" => Param {
+        name: "Stop",
+        min: 0,
+        max: 2,
+        default: 0,
+        labels: Some(&["Immediate", "Fade Out", "Loop End"]),
+        unit: None,
+        description: "\
+Specifies how the track will stop when you press the [STOP] switch.
+- The PLAY indicator will blink until playback stops.
+- If you press the [STOP] switch once again before playback stops, playback will stop immediately.
+- You can't overdubbing during the time until playback stops.
+
+Immediate: Playback will stop immediately.
+Fade Out: Playback will fade out and then stop. You can use 'Fade Time' (p. 5) to specify the length of the fade-out.
+Loop End: Playback will continue to the end of the loop, and then stop.
+",
+        value_descriptions: None,
+    },
+    "Measure" => Param {
+        name: "Measure",
+        min: 0,
+        max: UNKNOWN,
+        default: 1,
+        labels: None,
+        unit: None,
+        description: "\
+You can specify the number of measures for each track. When recording along with rhythm sounds or other tracks, it's convenient to specify the number of measures before you record, so that looping will occur at the specified measure length, even if you don't operate the switch when you've finished recording. 
+This parameter is available only if LOOP.S (p. 3) is 'On'.
+
+Track 1: One Measure   |---||---||---||---| |---||---||---||---|
+Track 2: Four Measures |----|----|----|---| |----|----|----|---|
+
+Auto: Tracks that are set to AUTO will have the same number of measures. The number of measures is determined by the first-recorded track of the tracks that are set to AUTO. For example, if all tracks are set to AUTO, the value set as the number of measures for the second and subsequent tracks will be identical to the number of measures in the first track that was recorded.
+
+Free: The number of measures will be set automatically, corresponding to the length of the recording.
+
+1 Measure - ... : The number of measures will be set manually. ",
+        value_descriptions: None,
+    },
+    "LoopSync" => Param {
+        name: "Loop Sync",
+        min: 0,
+        max: 1,
+        default: 1,
+        labels: Some(OFF_ON_LABELS),
+        unit: None,
+        description: "\
+Specifies whether the beginning of the track 1 and track 2 phrases are aligned for loop playback (On), or whether they loop-play at the length of their respective phrases (Off).
+
+On:  Track 1   |------------||------------|
+     Track 2   |----||----|  |----||----|
+                             ^-> Retriggers at the beginning of the first recorded phrase.
+
+Off: Track 1   |------------||------------|
+     Track 2   |----||----||----||----|
+                           ^-> Playback will repeate at the length of the phrase.
+
+
+If you turn LOOP.S on, the beginning of the tracks will always be aligned. This means that if tracks are already playing back, the playback start location of the other tracks will become 'the current location of the phrase being played.' The following illustration is an example of how playback will occur with track 1 and 2 that contain an identical melody but have different loop sync settings
+
+On:  Track 1   |-1-2-3-4-5-6-|
+     Track 2         |-4-5-6-|    <- Playback starts midway through the phrase (from the current location).
+
+Off:  Track 1   |-1-2-3-4-5-6-|
+     Track 2         |-1-2-3-4-5-6-|    <- Playback always starts at the beginning of the phrase.
+
+",
+        value_descriptions: None,
+    },
+    "TempoSync" => Param {
+        name: "Tempo Sync",
+        min: 0,
+        max: 1,
+        default: 1,
+        labels: Some(OFF_ON_LABELS),
+        unit: None,
+        description: "\
+Off: The track will play at its own original tempo.
+On: The track will play at the memory tempo.  Tracks 1 and 2 play at the same tempo.
+",
+        value_descriptions: None,
+    },
+    "Input" => Param {
+        name: "Input",
+        min: 0,
+        max: 5,
+        default: 0,
+        labels: Some(&["All", "MIC IN", "INST IN", "INST IN A", "INST IN B", "MIC/INST"]),
+        unit: None,
+        description: "\
+Specifies the input from which sound is recorded
+
+ALL: Sound that is input from the MIC IN jack and the INST IN jacks (A/MONO, B) is mixed and
+recorded.
+MIC IN: Sound that is input from the MIC IN jack is recorded in stereo.
+INST IN: Sound that is input to the INST IN jacks (A/MONO, B) is assigned and recorded to A and B (L, R).
+INST IN-A: Sound that is input from the INST IN A/MONO jack is recorded in stereo.
+INST IN-B: Sound that is input from the INST IN B jack is recorded in stereo.
+MIC/INST: Sound that is input to the MIC IN jack and the INST IN jacks (A/MONO, B) is assigned and recorded to A and B (L, R).
+",
+        value_descriptions: None,
+    },
+    "Output" => Param {
+        name: "Output",
+        min: 0,
+        max: 2,
+        default: 0,
+        labels: Some(&["All", "OUT A", "OUT B"]),
+        unit: None,
+        description: "\
+Specifies the output destination of the playback audio.
+
+ALL: Audio is output from both OUTPUT A/MONO and B jacks.
+OUT-A: Audio is output from the OUTPUT A/MONO jack.
+OUT-B: Audio is output from the OUTPUT B jack.
+",
+        value_descriptions: None,
+    },
+    "MeasMod" => Param {
+        name: "Measure Mode",
+        min: 0,
+        max: UNKNOWN,
+        default: 1,
+        labels: None,
+        unit: None,
+        description: "",
+        value_descriptions: None,
+    },
+    "MeasLen" => Param {
+        name: "Measure Length",
+        min: 0,
+        max: UNKNOWN,
+        default: 0,
+        labels: None,
+        unit: None,
+        description: "",
+        value_descriptions: None,
+    },
+    "MeasBtLp" => Param {
+        name: "Measure Beat Loop",
+        min: 0,
+        max: UNKNOWN,
+        default: 0,
+        labels: None,
+        unit: None,
+        description: "",
+        value_descriptions: None,
+    },
+    "RecTmp" => Param {
+        name: "Recorded Tempo",
+        min: 0,
+        max: UNKNOWN,
+        // Not in the guide and set by the user in both memories of the small config. 120 BPM is the recorded
+        // tempo of the tracks without audio.
+        default: 1200,
+        labels: None,
+        unit: Some("0.1 BPM"),
+        description: "",
+        value_descriptions: None,
+    },
+    "WavStat" => Param {
+        name: "Recorded",
+        min: 0,
+        max: 1,
+        default: 0,
+        labels: Some(OFF_ON_LABELS),
+        unit: None,
+        description: "",
+        value_descriptions: None,
+    },
+    "WavLen" => Param {
+        name: "Recording Length",
+        min: 0,
+        max: UNKNOWN,
+        default: 0,
+        labels: None,
+        unit: None,
+        description: "",
+        value_descriptions: None,
+    },
+};
+
+pub static MASTER: phf::OrderedMap<&'static str, Param> = phf_ordered_map! {
+    "Tempo" => Param {
+        name: "Tempo",
+        min: 0,
+        max: UNKNOWN,
+        default: 1200,
+        labels: None,
+        unit: Some("0.1 BPM"),
+        description: "",
+        value_descriptions: None,
+    },
+    "DubMode" => Param {
+        name: "Dub Mode",
+        min: 0,
+        max: 1,
+        default: 0,
+        labels: Some(&["Overdub", "Replace"]),
+        unit: None,
+        description: "\
+Specifies the overdubbing method.
+
+Overdub: The new performance is layered onto the prerecorded tracks.
+If overdubbing is repeated, the next performance is layered on top of the previous material,
+allowing you to create an ensemble in a single track.
+
+Replace: Tracks with existing recordings are overwritten as new tracks are recorded over them.
+Overwriting takes places while the previously recorded tracks are played back, allowing you
+to achieve a kind of delay effect similar to that obtained from an effects processor.
+",
+        value_descriptions: None,
+    },
+    "RecAction" => Param {
+        name: "Record Action",
+        min: 0,
+        max: 1,
+        default: 1,
+        labels: Some(&["Record -> Dub", "Record -> Play"]),
+        unit: None,
+        description: "\
+Specifies the order in which record/playback/overdubbing are switched when you press the [REC/PLAY] switch.
+
+Record -> Dub: Operation will switch in the order of Recording -> Overdubbing -> Playback.
+Record -> Play: Operation will switch in the order of Recording -> Playback -> Overdubbing.
+",
+        value_descriptions: None,
+    },
+    "RecQuantize" => Param {
+        name: "Record Quantize",
+        min: 0,
+        max: 1,
+        default: 0,
+        labels: Some(&["Off", "Measure"]),
+        unit: None,
+        description: "\
+With tracks for which Loop Sync is 'On', under any of the conditions listed below, your timing will be corrected
+(Loop Quantize) based on the tempo and time signature of the rhythm, even if the timing at which you press a button is
+slightly inaccurate.
+- If the rhythm is on
+- If there is an already-recorded track whose Loop Sync is turned on
+- If the MIDI Sync is on
+
+The Record Quantize setting applies only during recording. It is ignored during overdubbing or playback.
+When you stop recording, it is quantized to match the measure length of the previously-recorded track (Loop Sync: On) or
+rhythm.
+
+Off: Recording begins the instant you perform the operation. When you stop recording, it is quantized to match the measure.
+Measure: Quantize to the measure start location for recording.
+",
+        value_descriptions: None,
+    },
+    "AutoRec" => Param {
+        name: "Auto Record",
+        min: 0,
+        max: 1,
+        default: 0,
+        labels: Some(OFF_ON_LABELS),
+        unit: None,
+        description: "\
+Starts recording when there is audio input from your guitar performance or the mic.
+Off: Recording will begin the instant you press the [REC/PLAY] switch.
+On: When you press the [REC/PLAY] switch, the REC/PLAY indicator will blink rapidly, and the RC-500 will enter recording-standby mode.
+When you begin playing, the REC/PLAY indicator will light and recording will start.
+",
+        value_descriptions: None,
+    },
+    "AutoRecSens" => Param {
+        name: "Auto Record Sensitivity",
+        min: 0,
+        max: 100,
+        default: 50,
+        labels: None,
+        unit: None,
+        description: "Auto Record Sensitivity",
+        value_descriptions: None,
+    },
+    "AutoRecSrc" => Param {
+        name: "Auto Record Source",
+        min: 0,
+        max: 4,
+        default: 0,
+        labels: Some(&["All", "MIC IN", "INST", "INST A", "INST B"]),
+        unit: None,
+        description: "Auto Record Source",
+        value_descriptions: None,
+    },
+    "PlayMode" => Param {
+        name: "Play Mode",
+        min: 0,
+        max: 1,
+        default: 0,
+        labels: Some(&["Multi", "Single"]),
+        unit: None,
+        description: "\
+Specifies whether tracks 1 and 2 play back simultaneously.
+
+Multi:   Track 1   |-------------|
+         Track 2   |----------------------|
+
+Play back all tracks. To ensure that playback always takes place from the beginning of the phrase, you need to
+set Loop Sync to 'Off'.
+
+Single:  Track 1   |-------------|   <- Stops when the other track starts playing
+         Track 2                  |--------|
+
+Play back only a single track. The currently-playing track stops when the other track starts playing. If Single Change is set to 'Loop End' the track will change at the end of the loop.
+",
+        value_descriptions: None,
+    },
+    "SinglPlayeChange" => Param {
+        name: "Single Change",
+        min: 0,
+        max: 1,
+        default: 0,
+        labels: Some(&["Immediate", "Loop End"]),
+        unit: None,
+        description: "\
+Specifies how the tracks will be switched when Play Mode is 'Single'.
+Immediate: The change will occur immediately.
+Loop End: The change will occur after playback has reached the end of the loop.
+",
+        value_descriptions: None,
+    },
+    "FadeTime" => Param {
+        name: "Fade Time",
+        min: 0,
+        max: 6,
+        default: 5,
+        labels: None,
+        unit: None,
+        description: "Fade Time",
+        value_descriptions: None,
+    },
+    "AllStart" => Param {
+        name: "All Start",
+        min: 0,
+        max: 2,
+        default: 0,
+        labels: Some(&["All", "Track 1", "Track 2"]),
+        unit: None,
+        description: "\
+Normally, tracks 1 and 2 start playing simultaneously when a MIDI start message is received (the All setting). However, you can set this to make only the specified track (Track 1 or Track 2) start playing.
+",
+        value_descriptions: None,
+    },
+    "TrackChain" => Param {
+        name: "Track Chain",
+        min: 0,
+        max: 1,
+        default: 0,
+        labels: Some(&["Parallel", "Series"]),
+        unit: None,
+        description: "\
+Specifies how tracks 1 and 2 are connected.
+Parallel: The two loop tracks are connected in parallel.
+Series: The output of track 1 is connected to track 2.
+",
+        value_descriptions: None,
+    },
+    "CurrentTrack" => Param {
+        name: "Current Track",
+        min: 0,
+        max: 1,
+        default: 0,
+        labels: Some(&["Track 1", "Track 2"]),
+        unit: None,
+        description: "Current Track",
+        value_descriptions: None,
+    },
+    "AllTrackSel" => Param {
+        name: "All Tracks Selected",
+        min: 0,
+        max: 1,
+        default: 0,
+        labels: Some(&["Yes", "No"]),
+        unit: None,
+        description: "All Tracks Selected",
+        value_descriptions: None,
+    },
+    "Level" => Param {
+        name: "Memory Level",
+        min: 0,
+        max: 200,
+        default: 100,
+        labels: None,
+        unit: None,
+        description: "Adjusts the overall volume of the memory.",
+        value_descriptions: None,
+    },
+    "LpMod" => Param {
+        name: "Loop Mode",
+        min: 0,
+        max: UNKNOWN,
+        default: 1,
+        labels: None,
+        unit: None,
+        description: "",
+        value_descriptions: None,
+    },
+    "LpLen" => Param {
+        name: "Loop Length",
+        min: 0,
+        max: 25362,
+        default: 8,
+        labels: None,
+        unit: None,
+        description: "\
+Specifies the length to which Loop Sync aligns the beginnings of the material for loop sync.
+Auto: The length of the first-recorded phrase will be the Loop Length.
+1-25362: Manually specifies the number of measures that will be looped.    
+",
+        value_descriptions: None,
+    },
+    "TrkMod" => Param {
+        name: "Track Mode",
+        min: 0,
+        max: UNKNOWN,
+        default: 1,
+        labels: None,
+        unit: None,
+        description: "",
+        value_descriptions: None,
+    },
+    "Sync" => Param {
+        name: "Sync",
+        min: 0,
+        max: UNKNOWN,
+        default: 0,
+        labels: None,
+        unit: None,
+        description: "",
+        value_descriptions: None,
+    },
+};
+
+pub static LOOPFX: phf::OrderedMap<&'static str, Param> = phf_ordered_map! {
+    "Sw" => Param {
+        name: "Enabled",
+        min: 0,
+        max: 1,
+        default: 0,
+        labels: Some(OFF_ON_LABELS),
+        unit: None,
+        description: "Specifies whether the loop FX is on or off.",
+        value_descriptions: None,
+    },
+    "FxType" => Param {
+        name: "FX Type",
+        min: 0,
+        max: 9,
+        default: 4,
+        labels: Some(&[
+            "SCATTER1",
+            "SCATTER2",
+            "SCATTER3",
+            "SCATTER4",
+            "REPEAT1",
+            "REPEAT2",
+            "REPEAT3",
+            "SHIFT1",
+            "SHIFT2",
+            "VINYL FLICK",
+        ]),
+        unit: None,
+        description: "FX Type",
+        value_descriptions: None,
+    },
+    "RepeatLength" => Param {
+        name: "Repeat Length",
+        min: 0,
+        max: 6,
+        default: 3,
+        labels: None,
+        unit: None,
+        description: "Repeat Length",
+        value_descriptions: None,
+    },
+    "ShiftShift" => Param {
+        name: "Shift",
+        min: 0,
+        max: 6,
+        default: 3,
+        labels: None,
+        unit: None,
+        description: "Shift",
+        value_descriptions: None,
+    },
+    "ScatterLength" => Param {
+        name: "Scatter Length",
+        min: 0,
+        max: 6,
+        default: 2,
+        labels: None,
+        unit: None,
+        description: "Scatter Length",
+        value_descriptions: None,
+    },
+    "VinylFlickFlick" => Param {
+        name: "Vinyl Flick",
+        min: 0,
+        max: 100,
+        default: 50,
+        labels: None,
+        unit: None,
+        description: "Vinyl Flick",
+        value_descriptions: None,
+    },
+};
+
+pub static RHYTHM: phf::OrderedMap<&'static str, Param> = phf_ordered_map! {
+    "Level" => Param {
+        name: "Rhythm Level",
+        min: 0,
+        max: 200,
+        default: 100,
+        labels: None,
+        unit: None,
+        description: "Adjusts the volume of the rhythm.",
+        value_descriptions: None,
+    },
+    "Reverb" => Param {
+        name: "Reverb",
+        min: 0,
+        max: 100,
+        default: 30,
+        labels: None,
+        unit: None,
+        description: "",
+        value_descriptions: None,
+    },
+    "Pattern" => Param {
+        name: "Pattern",
+        min: 0,
+        max: UNKNOWN,
+        // SimpleBeat1, the first pattern in the guide, assuming the patterns are numbered in that order like the kits
+        default: 0,
+        labels: None,
+        unit: None,
+        description: "",
+        value_descriptions: None,
+    },
+    "Variation" => Param {
+        name: "Variation",
+        min: 0,
+        max: 1,
+        default: 0,
+        labels: Some(&["A", "B"]),
+        unit: None,
+        description: "",
+        value_descriptions: None,
+    },
+    "VariationChange" => Param {
+        name: "Variation Change",
+        min: 0,
+        max: 1,
+        default: 0,
+        labels: Some(&["Measure", "Loop End"]),
+        unit: None,
+        description: "",
+        value_descriptions: None,
+    },
+    "Kit" => Param {
+        name: "Kit",
+        min: 0,
+        max: 15,
+        default: 0,
+        labels: Some(&[
+            "Studio", "Live", "Light", "Heavy", "Rock", "Metal", "Jazz", "Brush", "Cajon", "Drum&Bass", "R&B", "Dance", "Techno", "Dance Beats", "HipHop",
+            "808+909",
+        ]),
+        unit: None,
+        description: "",
+        value_descriptions: None,
+    },
+    "Beat" => Param {
+        name: "Beat",
+        min: 0,
+        max: UNKNOWN,
+        default: 2,
+        labels: None,
+        unit: None,
+        description: "",
+        value_descriptions: None,
+    },
+    "Fill" => Param {
+        name: "Fill",
+        min: 0,
+        max: 1,
+        default: 1,
+        labels: Some(OFF_ON_LABELS),
+        unit: None,
+        description: "",
+        value_descriptions: None,
+    },
+    "Part1" => Param {
+        name: "Part 1",
+        min: 0,
+        max: 1,
+        default: 1,
+        labels: Some(OFF_ON_LABELS),
+        unit: None,
+        description: "",
+        value_descriptions: None,
+    },
+    "Part2" => Param {
+        name: "Part 2",
+        min: 0,
+        max: 1,
+        default: 1,
+        labels: Some(OFF_ON_LABELS),
+        unit: None,
+        description: "",
+        value_descriptions: None,
+    },
+    "Part3" => Param {
+        name: "Part 3",
+        min: 0,
+        max: 1,
+        default: 1,
+        labels: Some(OFF_ON_LABELS),
+        unit: None,
+        description: "",
+        value_descriptions: None,
+    },
+    "Part4" => Param {
+        name: "Part 4",
+        min: 0,
+        max: 1,
+        default: 0,
+        labels: Some(OFF_ON_LABELS),
+        unit: None,
+        description: "",
+        value_descriptions: None,
+    },
+    "RecCount" => Param {
+        name: "Record Count",
+        min: 0,
+        max: 1,
+        default: 0,
+        labels: Some(&["Off", "1 Measure"]),
+        unit: None,
+        description: "Record Count",
+        value_descriptions: None,
+    },
+    "PlayCount" => Param {
+        name: "Play Count",
+        min: 0,
+        max: 1,
+        default: 0,
+        labels: Some(&["Off", "1 Measure"]),
+        unit: None,
+        description: "Play Count",
+        value_descriptions: None,
+    },
+    "Start" => Param {
+        name: "Start",
+        min: 0,
+        max: 2,
+        default: 0,
+        labels: Some(&["Loop Start", "Record End", "Before Loop"]),
+        unit: None,
+        description: "",
+        value_descriptions: None,
+    },
+    "Stop" => Param {
+        name: "Stop",
+        min: 0,
+        max: 2,
+        default: 1,
+        labels: Some(&["Never", "Loop Stop", "Record End"]),
+        unit: None,
+        description: "",
+        value_descriptions: None,
+    },
+    "ToneLow" => Param {
+        name: "Tone Low",
+        min: 0,
+        max: 20,
+        default: 10,
+        labels: None,
+        unit: None,
+        description: "Tone Low",
+        value_descriptions: None,
+    },
+    "ToneHigh" => Param {
+        name: "Tone High",
+        min: 0,
+        max: 20,
+        default: 10,
+        labels: None,
+        unit: None,
+        description: "Tone High",
+        value_descriptions: None,
+    },
+    "State" => Param {
+        name: "Current State",
+        min: 0,
+        max: 2,
+        default: 1,
+        labels: None,
+        unit: None,
+        description: "Current State",
+        value_descriptions: None,
+    },
+};
+
+pub static CTL: phf::OrderedMap<&'static str, Param> = phf_ordered_map! {
+    "Pedal1" => Param {
+        name: "Pedal 1",
+        min: 0,
+        max: 57,
+        default: 28,
+        labels: Some(PEDAL_CTL_LABELS),
+        unit: None,
+        description: "Specify the functions of the [REC/PLAY] switch (PDL1)",
+        value_descriptions: Some(PEDAL_VALUE_DESCRIPTIONS),
+    },
+    "Pedal2" => Param {
+        name: "Pedal 2",
+        min: 0,
+        max: 57,
+        default: 36,
+        labels: Some(PEDAL_CTL_LABELS),
+        unit: None,
+        description: "Specify the functions of the [STOP] switch (PDL2)",
+        value_descriptions: Some(PEDAL_VALUE_DESCRIPTIONS),
+    },
+    "Pedal3" => Param {
+        name: "Pedal 3",
+        min: 0,
+        max: 57,
+        default: 27,
+        labels: Some(PEDAL_CTL_LABELS),
+        unit: None,
+        description: "Specify the functions of the [TRACK SELECT] switch (PDL3)",
+        value_descriptions: Some(PEDAL_VALUE_DESCRIPTIONS),
+    },
+    "Ctl1" => Param {
+        name: "Control 1",
+        min: 0,
+        max: 57,
+        default: 44,
+        labels: Some(PEDAL_CTL_LABELS),
+        unit: None,
+        description: "Specify the functions of the footswitch connected to the CTL 1, 2/EXP jack",
+        value_descriptions: Some(PEDAL_VALUE_DESCRIPTIONS),
+    },
+    "Ctl2" => Param {
+        name: "Control 2",
+        min: 0,
+        max: 57,
+        default: 53,
+        labels: Some(PEDAL_CTL_LABELS),
+        unit: None,
+        description: "Specify the functions of the footswitch connected to the CTL 1, 2/EXP jack",
+        value_descriptions: Some(PEDAL_VALUE_DESCRIPTIONS),
+    },
+    "Exp" => Param {
+        name: "Expression Pedal",
+        min: 0,
+        max: 13,
+        default: 13,
+        labels: Some(&[
+            "Off", "T1 Level1", "T1 Level2", "T2 Level1", "T2 Level2", "Current Level1", "Current Level2",
+            "Tempo Up", "Tempo Down", "FX Control", "Rhythm Level1", "Rhythm Level2", "Memory Level1", "Memory Level2",
+        ]),
+        unit: None,
+        description: "Specifies the function of a expression pedal connected to the CTL 1, 2/EXP jack.",
+        value_descriptions: Some(&[
+            "No function is assigned.",
+            "Control the 'LEVEL' of Track 1 in the range of 0-200.",
+            "Control the level in the range of 0-'maximum value', with the 'LEVEL' setting of Track 1 as the maximum value.",
+            "Control the 'LEVEL' of Track 2 in the range of 0-200.",
+            "Control the level in the range of 0-'maximum value', with the 'LEVEL' setting of Track 2 as the maximum value.",
+            "Control the 'LEVEL' of the currently selected Track in the range of 0-200.",
+            "Control the level of the currently selected Track in the range of 0-'maximum value', with the 'LEVEL' setting of the currently selected Track as the maximum value.",
+            "Press the pedal to make the tempo faster.",
+            "Press the pedal to make the tempo slower.",
+            "Control a parameter according to the loop FX type.",
+            "Control the 'LEVEL' of rhythm in the range of 0-200.",
+            "Control the level in the range of 0-'maximum value', with the 'LEVEL' setting of rhythm as the maximum value.",
+            "Control the 'LEVEL' of memory in the range of 0-200.",
+            "Control the level in the range of 0-'maximum value', with the 'LEVEL' setting of memory as the maximum value.",
+        ]),
+    },
+};
+
+pub static ASSIGN: phf::OrderedMap<&'static str, Param> = phf_ordered_map! {
+    "Sw" => Param {
+        name: "Enabled",
+        min: 0,
+        max: 1,
+        default: 0,
+        labels: Some(OFF_ON_LABELS),
+        unit: None,
+        description: "Specifies whether this assign is used (On) or not (Off).",
+        value_descriptions: None,
+    },
+    "Source" => Param {
+        name: "Source",
+        min: 0,
+        max: 12 + 31 + (95 - 64),
+        default: 0,
+        labels: Some(&[
+            "Pedal 1", "Pedal 2", "Pedal 3", "Expression Pedal", "CTL1 Pedal", "CTL2 Pedal", "TR1 Knob", "TR2 Knob",
+            "TR1 Play/Stop", "TR2 Play/Stop", "Current Track Change", "Sync Start",
+            "CC 1", "CC 2", "CC 3", "CC 4", "CC 5", "CC 6", "CC 7", "CC 8", "CC 9", "CC 10", "CC 11", "CC 12", "CC 13", "CC 14", "CC 15", "CC 16", "CC 17", "CC 18", "CC 19", "CC 20", "CC 21", "CC 22", "CC 23", "CC 24", "CC 25", "CC 26", "CC 27", "CC 28", "CC 29", "CC 30", "CC 31", "CC 64", "CC 65", "CC 66", "CC 67", "CC 68", "CC 69", "CC 70", "CC 71", "CC 72", "CC 73", "CC 74", "CC 75", "CC 76", "CC 77", "CC 78", "CC 79", "CC 80", "CC 81", "CC 82", "CC 83", "CC 84", "CC 85", "CC 86", "CC 87", "CC 88", "CC 89", "CC 90", "CC 91", "CC 92", "CC 93", "CC 94", "CC 95",
+        ]),
+        unit: None,
+        description: "Specify the controller (source) that will control the target.",
+        value_descriptions: Some(&[
+            "[REC/PLAY] switch",
+            "[STOP] switch",
+            "[TRACK SELECT] switch",
+            "An Expression pedal connected to the CTL 1, 2/EXP jack",
+            "A footswitch (CTL1, CTL2) connected to the CTL 1, 2/EXP jack",
+            "A footswitch (CTL1, CTL2) connected to the CTL 1, 2/EXP jack",
+            "[TRACK 1] slider",
+            "[TRACK 2] slider",
+            "When track 1 playback starts or stops",
+            "When track 2 playback starts or stops",
+            "When the track for record/playback is switched",
+            "All Start message from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+            "Control Change message (1-31, 64-95) from an external MIDI device",
+        ]),
+    },
+    "SourceMode" => Param {
+        name: "Source Mode",
+        min: 0,
+        max: 1,
+        default: 0,
+        labels: Some(&["Moment", "Toggle"]),
+        unit: None,
+        description: "\
+If a momentary-type footswitch (such as the separately sold FS-5U) is connected as the source, you can specify how footswitch operations will affect the value.",
+        value_descriptions: Some(&[
+            "The setting will normally be off (minimum value); it will be on (maximum value) only while you continue holding down the footswitch.",
+            "The setting will alternate between off (minimum value) and on (maximum value) each time you press the footswitch."
+        ]),
+    },
+    "Target" => Param {
+        name: "Target",
+        min: 0,
+        max: 77 + 31 + (95 - 64),
+        default: 0,
+        labels: Some(&[
+            "T1 Record/Play", "T1 Play/Stop", "T1 Clear", "T1 Undo/Redo", "T1 Reverse", "T1 1Shot", "T1 Level1", "T1 Level2",
+            "T1 Pan", "T1 Start", "T1 Stop", "T1 Loop Sync", "T1 Tempo Sync", "T1 Input", "T1 Output",
+
+            "T2 Record/Play", "T2 Play/Stop", "T2 Clear", "T2 Undo/Redo", "T2 Reverse", "T2 1Shot", "T2 Level1", "T2 Level2",
+            "T2 Pan", "T2 Start", "T2 Stop", "T2 Loop Sync", "T2 Tempo Sync", "T2 Input", "T2 Output",
+
+            "TRK SELECT",
+            "Current Record/Play", "Current Play/Stop", "Current Clear", "Current Undo/Redo", "Current Reverse", "Current 1Shot", "Current Level1", "Current Level2",
+            "Current Pan", "Current Start", "Current Stop", "Current Loop Sync", "Current Tempo Sync", "Current Input", "Current Output",
+
+            "Undo/Redo", "All Start", "Tap Tempo", "Tempo Up", "Tempo Down", "Tempo", "Dub Mode", "Record Action",
+            "Auto Record", "Auto Record Sensitivity", "Auto Rec Source", "Loop Length", "Play Mode", "Single Change", "Fade Time",
+            "All Start Track", "Track Chain", "Loop FX", "TR1 FX", "TR2 FX", "Current Track FX", "FX Type", "FX Inc", "FX Dec",
+            "FX Control",
+            "Rhythm Play/Stop", "Rhythm Play", "Rhythm Stop", "Rhythm Level1", "Rhythm Level2", "Rhythm Reverb",
+            "Rhythm Pattern", "Variation", "Variation Change", "Kit", "Rhythm Start", "Rhythm Stop", "Record Count",
+            "Play Count", "Rhythm Fill",
+            "Rhythm Part1", "Rhythm Part2", "Rhythm Part3", "Rhythm Part4",
+            "Tone Low", "Tone High",
+            "Memory Inc", "Memory Dec", "Memory Level1", "Memory Level2",
+            "CC 1", "CC 2", "CC 3", "CC 4", "CC 5", "CC 6", "CC 7", "CC 8", "CC 9",
+            "CC 10", "CC 11", "CC 12", "CC 13", "CC 14", "CC 15", "CC 16", "CC 17", "CC 18", "CC 19",
+            "CC 20", "CC 21", "CC 22", "CC 23", "CC 24", "CC 25", "CC 26", "CC 27", "CC 28", "CC 29",
+            "CC 30", "CC 31",
+
+            "CC 64", "CC 65", "CC 66", "CC 67", "CC 68", "CC 69",
+            "CC 70", "CC 71", "CC 72", "CC 73", "CC 74", "CC 75", "CC 76", "CC 77", "CC 78", "CC 79",
+            "CC 80", "CC 81", "CC 82", "CC 83", "CC 84", "CC 85", "CC 86", "CC 87", "CC 88", "CC 89",
+            "CC 90", "CC 91", "CC 92", "CC 93", "CC 94", "CC 95"
+        ]),
+        unit: None,
+        description: "\
+Specifies the function that is controlled. Depending on the specified function, control might not be possible while playing a track or the rhythm.",
+        value_descriptions: Some(ASSIGN_TARGET_VALUE_DESCRIPTIONS),
+    },
+    "TargetMin" => Param {
+        name: "Target Min",
+        min: 0,
+        max: UNKNOWN,
+        default: 0,
+        labels: None,
+        unit: None,
+        description: "\
+Specifies the variable range of the function (parameter) that is specified as the target.
+The value (MIN: minimum value, MAX: maximum value) depends on the parameter that is specified as the target. 
+",
+        value_descriptions: None,
+    },
+    "TargetMax" => Param {
+        name: "Target Max",
+        min: 0,
+        max: UNKNOWN,
+        default: 1,
+        labels: None,
+        unit: None,
+        description: "\
+Specifies the variable range of the function (parameter) that is specified as the target.
+The value (MIN: minimum value, MAX: maximum value) depends on the parameter that is specified as the target. 
+",
+        value_descriptions: None,
+    },
+};
+
+static OFF_ON_LABELS: &[&str] = &["Off", "On"];
+
+static PEDAL_CTL_LABELS: &[&str] = &[
+    "Off",
+    "T1 Rec/Play",
+    "T1 Record/Play/Stop",
+    "T1 Record/Play/Stop(Clear)",
+    "T1 Moment Record/Play",
+    "T1 Play/Stop",
+    "T1 Play/Stop(Clear)",
+    "T1 Stop",
+    "T1 Stop(Tempo Tap)",
+    "T1 Stop(Clear)",
+    "T1 Stop(Tempo Tab/Clear)",
+    "T1 Clear",
+    "T1 Undo/Redo",
+    "T1 Reverse",
+    "T2 Rec/Play",
+    "T2 Record/Play/Stop",
+    "T2 Record/Play/Stop(Clear)",
+    "T2 Moment Record/Play",
+    "T2 Play/Stop",
+    "T2 Play/Stop(Clear)",
+    "T2 Stop",
+    "T2 Stop(Tempo Tap)",
+    "T2 Stop(Clear)",
+    "T2 Stop(Tempo Tab/Clear)",
+    "T2 Clear",
+    "T2 Undo/Redo",
+    "T2 Reverse",
+    "Track Select",
+    "Current Rec/Play",
+    "Current Record/Play/Stop",
+    "Current Record/Play/Stop(Clear)",
+    "Current Moment Record/Play",
+    "Current Play/Stop",
+    "Current Play/Stop(Clear)",
+    "Current Stop",
+    "Current Stop(Tempo Tap)",
+    "Current Stop(Clear)",
+    "Current Stop(Tempo Tab/Clear)",
+    "Current Clear",
+    "Current Undo/Redo",
+    "Current Reverse",
+    "Undo/Redo",
+    "All Start",
+    "Tap Tempo",
+    "Loop FX",
+    "Track1 FX",
+    "Track2 FX",
+    "Current Track FX",
+    "FX Inc",
+    "FX Dec",
+    "Rhythm Play/Stop",
+    "Rhythm Play",
+    "Rhythm Stop",
+    "Memory Inc",
+    "Memory Dec",
+    "Mic Mute",
+    "Extent Inc",
+    "Extent Dec",
+];
+
+static PEDAL_VALUE_DESCRIPTIONS: &[&str] = &[
+"No function is assigned.",
+"Switch between record/play/overdubbing for track 1.  Long press (two seconds or longer) the switch during playback or overdubbing to Undo, long press the switch once again to Redo.",
+"Switch between record/play/stop (press the switch twice) for track 1.  Long press (two seconds or longer) the switch during recording or playback to Undo, long press the switch once again to Redo.",
+"Switch between record/play/stop (press the switch twice) for track 1.  Long press (two seconds or longer) the switch during recording or playback to Undo, long press the switch once again to Redo.  Long press (two seconds or longer) the switch during stopped, the track is cleared.",
+"Put track 1 in record/play only while you hold down the switch.",
+"Switch between play/stop for track 1.",
+"Switch between play/stop for track 1.  Long press (two seconds or longer) the switch during recording or playback to Undo, long press the switch once again to Redo.  Long press (two seconds or longer) the switch during stopped, the track is cleared.",
+"Stop record/play for track 1.",
+"Stop record/play for track 1.  Specify the tempo (tap tempo) by pressing the switch several times at the desired interval while stopped.",
+"Stop record/play for track 1.  Long press (two seconds or longer) the switch during stopped, the track is cleared.",
+"Stop record/play for track 1.  Specify the tempo (tap tempo) by pressing the switch several times at the desired interval while stopped.  Long press (two seconds or longer) the switch during stopped, the track is cleared.",
+"Clear track 1.",
+"Undo/redo recording or the most recent overdubbing for track 1.",
+"Turn reverse play on/off for track 1.",
+"Switch between record/play/overdubbing for track 2.  Long press (two seconds or longer) the switch during playback or overdubbing to Undo, long press the switch once again to Redo.",
+"Switch between record/play/stop (press the switch twice) for track 2.  Long press (two seconds or longer) the switch during recording or playback to Undo, long press the switch once again to Redo.",
+"Switch between record/play/stop (press the switch twice) for track 2.  Long press (two seconds or longer) the switch during recording or playback to Undo, long press the switch once again to Redo.  Long press (two seconds or longer) the switch during stopped, the track is cleared.",
+"Put track 2 in record/play only while you hold down the switch.",
+"Switch between play/stop for track 2.",
+"Switch between play/stop for track 2.  Long press (two seconds or longer) the switch during recording or playback to Undo, long press the switch once again to Redo.  Long press (two seconds or longer) the switch during stopped, the track is cleared.",
+"Stop record/play for track 2.",
+"Stop record/play for track 2.  Specify the tempo (tap tempo) by pressing the switch several times at the desired interval while stopped.",
+"Stop record/play for track 2.  Long press (two seconds or longer) the switch during stopped, the track is cleared.",
+"Stop record/play for track 2.  Specify the tempo (tap tempo) by pressing the switch several times at the desired interval while stopped.  Long press (two seconds or longer) the switch during stopped, the track is cleared.",
+"Clear track 2.",
+"Undo/redo recording or the most recent overdubbing for track 2.",
+"Turn reverse play on/off for track 2.",
+"Switch between tracks 1 and 2. Long-press the switch to select both tracks.  If both tracks are selected, operating the switch assigned to CUR REC/PLY lets you control play/stop for both tracks.",
+"Switch between record/play/overdubbing for current track.  Long press (two seconds or longer) the switch during playback or overdubbing to Undo, long press the switch once again to Redo.",
+"Switch between record/play/stop (press the switch twice) for current track.  Long press (two seconds or longer) the switch during recording or playback to Undo, long press the switch once again to Redo.",
+"Switch between record/play/stop (press the switch twice) for current track.  Long press (two seconds or longer) the switch during recording or playback to Undo, long press the switch once again to Redo.  Long press (two seconds or longer) the switch during stopped, the track is cleared.",
+"Put current track in record/play only while you hold down the switch.",
+"Switch between play/stop for current track.",
+"Switch between play/stop for current track.  Long press (two seconds or longer) the switch during recording or playback to Undo, long press the switch once again to Redo.  Long press (two seconds or longer) the switch during stopped, the track is cleared.",
+"Stop record/play for current track.",
+"Stop record/play for current track.  Specify the tempo (tap tempo) by pressing the switch several times at the desired interval while stopped.",
+"Stop record/play for current track.  Long press (two seconds or longer) the switch during stopped, the track is cleared.",
+"Stop record/play for current track.  Specify the tempo (tap tempo) by pressing the switch several times at the desired interval while stopped.  Long press (two seconds or longer) the switch during stopped, the track is cleared.",
+"Clear current track.",
+"Undo/redo recording or the most recent overdubbing for current track.",
+"Turn reverse play on/off for current track.",
+"Simultaneously start play for tracks 1 and 2.",
+"Press the switch several times at the desired interval to specify the tempo.",
+"Long-press the switch (two seconds or longer) to return to the previous tempo.",
+"Turn the loop FX on/off.",
+"Turn the loop FX on/off for track 1.",
+"Turn the loop FX on/off for track 2.",
+"Turn the loop FX on/off for the currently selected track.",
+"Switch the loop FX in the order of SCATTER 1-4 -> REPEAT 1-3 -> SHIFT 1-2 -> VINYL FLICK -> SCATTER1...",
+"Switch the loop FX in the order of VINYL FLICK -> SHIFT 2-1 -> REPEAT 3-1 -> SCATTER 4-1 -> VINYL FLICK...",
+"Switch the rhythm between play/stop.",
+"Play the rhythm.",
+"Stop playing the rhythm.",
+"Switch to the next memory.",
+"Switch to the previous memory.",
+"Mute the audio from the mic.",
+"Switch the 'EXTENT' (p. 13) setting in the order of EXT10EXT2...EXT50...",
+"Switch the 'EXTENT' (p. 13) setting in the order of EXT50EXT4...EXT10...",
+];
+
+static ASSIGN_TARGET_VALUE_DESCRIPTIONS: &[&str] = &[
+"Switch between record/play/overdubbing for Track 1.",
+"Switch between play/stop for Track 1.",
+"Clear Track 1.",
+"Undo/redo recording or the most recent overdubbing for Track 1.",
+"Control 'REVERSE' for Track 1.",
+"Control '1SHOT' for Track 1.",
+"Control the 'LEVEL' (p. 2) of Track 1 in the range of 0-200.",
+"Control the level in the range of 0-'maximum value,' with the 'LEVEL' setting of Track 1 as the maximum value.",
+"Control 'PAN' for Track 1.",
+"Control 'START' for Track 1.",
+"Control 'STOP' for Track 1.",
+"Control 'LOOP.S' for Track 1.",
+"Control 'TEMPO.S' for Track 1.",
+"Control 'INPUT' for Track 1.",
+"Control 'OUTPUT' for Track 1.",
+"Switch between record/play/overdubbing for Track 2.",
+"Switch between play/stop for Track 2.",
+"Clear Track 2.",
+"Undo/redo recording or the most recent overdubbing for Track 2.",
+"Control 'REVERSE' for Track 2.",
+"Control '1SHOT' for Track 2.",
+"Control the 'LEVEL' (p. 2) of Track 2 in the range of 0-200.",
+"Control the level in the range of 0-'maximum value,' with the 'LEVEL' setting of Track 2 as the maximum value.",
+"Control 'PAN' for Track 2.",
+"Control 'START' for Track 2.",
+"Control 'STOP' for Track 2.",
+"Control 'LOOP.S' for Track 2.",
+"Control 'TEMPO.S' for Track 2.",
+"Control 'INPUT' for Track 2.",
+"Control 'OUTPUT' for Track 2.",
+"Switch between tracks 1 and 2.",
+"Switch between record/play/overdubbing for the currently selected track.",
+"Clear the currently selected track.",
+"Undo/redo recording or the most recent overdubbing for the currently selected track.",
+"Control 'REVERSE' for the currently selected track.",
+"Control '1SHOT' for the currently selected track.",
+"Control the 'LEVEL' of the currently selected track in the range of 0-200.",
+"Control the level in the range of 0-'maximum value,' with the 'LEVEL' setting of track 1 or 2 as the maximum value.",
+"Control 'PAN' for the currently selected track.",
+"Control 'START' for the currently selected track.",
+"Control 'STOP' for the currently selected track.",
+"Control 'LOOP.S' for the currently selected track.",
+"Control 'TEMPO.S' for the currently selected track.",
+"Control 'INPUT' for the currently selected track.",
+"Control 'OUTPUT' for the currently selected track.",
+
+"Allows you to undo/redo the last recording or overdubbing of a track.",
+"Simultaneously start play for tracks 1 and 2.",
+"Press the switch several times at the desired interval to specify the tempo.",
+"Operate the pedal to make the tempo faster.",
+"Operate the pedal to make the tempo slower.",
+"Control the tempo.",
+"Control 'DUB MODE' of memory/REC.",
+"Control 'REC ACTION' of memory/REC.",
+"Control 'QUANTIZE' of memory/REC.",
+"Control 'AUTO REC' of memory/REC.",
+"Control 'A.REC SENS' of memory/REC.",
+"Control 'A.REC SRC' of memory/REC.",
+"Control 'LOOP LENGTH' of memory/REC.",
+"Control 'PLAY MODE' of memory/REC.",
+"Control 'SINGL CHNGE' of memory/REC.",
+"Control 'FADE TIME' of memory/REC.",
+"Control 'ALL START' of memory/REC.",
+"Control 'TRK CHAIN' of memory/REC.",
+"Turn the loop FX on/off.",
+"Turn the loop FX on/off for track 1.",
+"Turn the loop FX on/off for track 2.",
+"Turn the loop FX on/off for the currently selected track.",
+"Control 'TYPE' of memory/LOOP FX.",
+"Switch the loop FX in the order of SCATTER1-40REPEAT1-30SHIFT1-20VINYL FLICK0 SCATTER1...",
+"Switch the loop FX in the order of VINYL FLICK0SHIFT2-10REPEAT3-10SCATTER4-10 VINYL FLICK...",
+"Control a parameter according to the loop FX type.",
+"Switch the rhythm between play/stop.",
+"Play the rhythm.",
+"Stop playing the rhythm.",
+"Control the 'LEVEL' (p. 6) of rhythm in the range of 0-200.",
+"Control the level in the range of 0-'maximum value,' with the 'LEVEL' setting of rhythm as the maximum value.",
+"Control 'REVERB' of memory/RHYTHM.",
+"Control 'PATTERN' of memory/RHYTHM.",
+"Control 'VARIATION' of memory/RHYTHM.",
+"Control 'VAR.CHANGE' of memory/RHYTHM.",
+"Control 'KIT' of memory/RHYTHM.",
+"Control 'START' of memory/RHYTHM.",
+"Control 'STOP' of memory/RHYTHM.",
+"Control 'REC COUNT' of memory/RHYTHM.",
+"Control 'PLAY COUNT' of memory/RHYTHM.",
+"Control 'FILL' of memory/RHYTHM.",
+"Control 'PART1'-'PART4' of memory/RHYTHM.",
+"Control 'TONE LOW' of memory/RHYTHM.",
+"Control 'TONE HIGH' of memory/RHYTHM.",
+"Switch to the next memory.",
+"Switch to the previous memory.",
+"Control the 'LEVEL' (p. 5) of memory in the range of 0-200.",
+"Control the level in the range of 0-'maximum value,' with the 'LEVEL' setting of memory as the maximum value.",
+
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+"Transmit a control change message of the specified controller number from the MIDI OUT connector.",
+];
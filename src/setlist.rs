@@ -0,0 +1,119 @@
+//! Putting the memories in the order of a setlist, so that song N is in memory N.
+//!
+//! A setlist is a TOML file that lists the songs in the order in which they are played, by memory name or
+//! by memory number:
+//!
+//! ```toml
+//! songs = ["INTRO", "Verse loop", 12]
+//! ```
+//!
+//! Memories that are not in the setlist keep their order and follow after the songs.
+
+use crate::error::Rc500Error;
+use crate::model;
+use crate::slots;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct Setlist {
+    pub songs: Vec<Song>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum Song {
+    /// The memory number as shown on the device, counting from 1
+    Number(usize),
+    Name(String),
+}
+
+/// Read the text of a setlist. The file of a parse error is "setlist", callers that know the file can
+/// replace it.
+pub fn parse(text: &str) -> Result<Setlist, Rc500Error> {
+    toml::from_str(text).map_err(|e| Rc500Error::parse("setlist", e.to_string()))
+}
+
+/// The ids of the memories in their new order.
+pub fn order(config: &model::Config, setlist: &Setlist) -> Result<Vec<usize>, Rc500Error> {
+    let mut order: Vec<usize> = Vec::new();
+    for song in &setlist.songs {
+        let id = find(config, song)?;
+        if order.contains(&id) {
+            return Err(Rc500Error::invalid_value(format!(
+                "Memory {} is in the setlist more than once",
+                id + 1
+            )));
+        }
+        order.push(id);
+    }
+    let rest: Vec<usize> = config
+        .memories
+        .iter()
+        .map(|memory| memory.id)
+        .filter(|id| !order.contains(id))
+        .collect();
+    order.extend(rest);
+    Ok(order)
+}
+
+fn find(config: &model::Config, song: &Song) -> Result<usize, Rc500Error> {
+    match song {
+        Song::Number(number) => config
+            .memories
+            .iter()
+            .find(|memory| memory.id + 1 == *number)
+            .map(|memory| memory.id)
+            .ok_or_else(|| Rc500Error::invalid_value(format!("There is no memory {}", number))),
+        Song::Name(name) => {
+            let matches: Vec<usize> = config
+                .memories
+                .iter()
+                .filter(|memory| model::get_memory_name(memory).trim().eq_ignore_ascii_case(name.trim()))
+                .map(|memory| memory.id)
+                .collect();
+            match matches[..] {
+                [id] => Ok(id),
+                [] => Err(Rc500Error::invalid_value(format!(
+                    "There is no memory named '{}'",
+                    name
+                ))),
+                _ => Err(Rc500Error::invalid_value(format!(
+                    "There are {} memories named '{}', use the number of the memory instead",
+                    matches.len(),
+                    name
+                ))),
+            }
+        }
+    }
+}
+
+/// The plan that puts memory `order[i]` in slot `i`.
+pub fn plan(config: &model::Config, order: &[usize]) -> slots::Plan {
+    config
+        .memories
+        .iter()
+        .zip(order)
+        .filter(|(memory, source)| memory.id != **source)
+        .map(|(memory, source)| (memory.id, slots::Source::Memory(*source)))
+        .collect()
+}
+
+/// A table of the memories that move, from their old to their new slot.
+pub fn preview(config: &model::Config, order: &[usize]) -> String {
+    let mut lines = vec![format!("{:>8}    {:>8}  {}", "Old slot", "New slot", "Name")];
+    for (memory, source) in config.memories.iter().zip(order) {
+        if memory.id != *source {
+            let name = config
+                .memories
+                .iter()
+                .find(|m| m.id == *source)
+                .map(model::get_memory_name)
+                .unwrap_or_default();
+            lines.push(format!("{:>8} -> {:>8}  {}", source + 1, memory.id + 1, name));
+        }
+    }
+    if lines.len() == 1 {
+        return "The memories are already in the order of the setlist".to_string();
+    }
+    lines.join("\n")
+}
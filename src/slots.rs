@@ -0,0 +1,312 @@
+//! Operations on whole memory slots: copy, swap, move, clear, and resetting a menu.
+//!
+//! Every operation is expressed as a plan that says, for each slot that changes, where its new content comes
+//! from. The ids of the memories belong to the slots and never change, so they keep matching the position of
+//! the memory in the file.
+//!
+//! The audio of a recorded track is not in the memory file, but in the folder `<memory>_<track>` of the WAVE
+//! folder of the device, like `007_1` for the first track of memory 7. A track has audio if its WavStat is
+//! set. When memories with audio are rearranged, their folders are rearranged the same way, in two steps: the
+//! new folders are staged by [`apply`], and only put in place by [`commit_audio`] once the memories they
+//! belong to are on the device. If the WAVE folder is not available, such operations are refused, because the
+//! device would end up with memories that point to the wrong audio.
+
+use crate::error::Rc500Error;
+use crate::model;
+use crate::schema;
+use crate::trailer::{self, Trailer};
+use crate::typed;
+use std::fs;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+
+const NR_TRACKS: usize = 2;
+
+/// The settings of a track that describe its recorded audio. Resetting a track menu keeps them, so the memory
+/// still matches its audio.
+const RECORDING_KEYS: [&str; 3] = ["RecTmp", "WavStat", "WavLen"];
+
+/// Where the new content of a slot comes from. Slots are given by memory id.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Source {
+    /// The whole memory in this slot, name included
+    Memory(usize),
+    /// The settings of the memory in this slot, keeping the name of the target
+    Settings(usize),
+    /// A fresh memory with default settings and name
+    Defaults,
+}
+
+/// The new content of the slots that change, by memory id.
+pub type Plan = Vec<(usize, Source)>;
+
+/// Copy the settings of a memory to every slot in the range, keeping their names.
+pub fn copy(from: usize, to: RangeInclusive<usize>) -> Plan {
+    to.filter(|target| *target != from)
+        .map(|target| (target, Source::Settings(from)))
+        .collect()
+}
+
+pub fn swap(a: usize, b: usize) -> Plan {
+    vec![(a, Source::Memory(b)), (b, Source::Memory(a))]
+}
+
+/// Move a memory to another slot, shifting the memories in between by one towards the slot it left.
+pub fn move_to(from: usize, to: usize) -> Plan {
+    let mut plan = vec![(to, Source::Memory(from))];
+    if from < to {
+        plan.extend((from..to).map(|slot| (slot, Source::Memory(slot + 1))));
+    } else {
+        plan.extend((to + 1..=from).map(|slot| (slot, Source::Memory(slot - 1))));
+    }
+    plan
+}
+
+pub fn clear(slot: usize) -> Plan {
+    vec![(slot, Source::Defaults)]
+}
+
+/// Parse a memory number like `7` or a range like `10-15`, counting from 1, into memory ids.
+pub fn parse_range(text: &str) -> Result<RangeInclusive<usize>, Rc500Error> {
+    let number = |text: &str| match text.trim().parse::<usize>() {
+        Ok(number) if number >= 1 => Ok(number - 1),
+        _ => Err(Rc500Error::invalid_value(format!(
+            "'{}' is not a memory number, which counts from 1",
+            text
+        ))),
+    };
+    match text.split_once('-') {
+        None => number(text).map(|id| id..=id),
+        Some((first, last)) => {
+            let (first, last) = (number(first)?, number(last)?);
+            if first > last {
+                return Err(Rc500Error::invalid_value(format!(
+                    "'{}' is not a range, the first memory comes after the last",
+                    text
+                )));
+            }
+            Ok(first..=last)
+        }
+    }
+}
+
+/// Parse a list of memory numbers and ranges like `5,7-9`, counting from 1, into memory ids in ascending order.
+pub fn parse_list(text: &str) -> Result<Vec<usize>, Rc500Error> {
+    let mut ids = Vec::new();
+    for part in text.split(',') {
+        ids.extend(parse_range(part)?);
+    }
+    ids.sort_unstable();
+    ids.dedup();
+    Ok(ids)
+}
+
+/// A fresh memory with the default value of every setting.
+pub fn default_memory(id: usize) -> model::Memory {
+    let menus = typed::menu_names()
+        .into_iter()
+        .map(|name| {
+            let content = match schema::menu_params(&name) {
+                None => model::MenuContent::StringValueMenu(model::StringValueMenu {
+                    value: default_name(id),
+                }),
+                Some(params) => model::MenuContent::KeyValueMenu(model::UntypedMenu {
+                    settings: params
+                        .entries()
+                        .map(|(key, param)| model::UntypedKeyValue {
+                            key: key.to_string(),
+                            value: param.default,
+                        })
+                        .collect(),
+                }),
+            };
+            model::Menu { name, content }
+        })
+        .collect();
+    model::Memory { id, menus }
+}
+
+pub fn default_name(id: usize) -> String {
+    format!("Memory{:02}", id + 1)
+}
+
+/// A complete bank of memories with default settings, as the first generation.
+pub fn default_config(filename: &str) -> model::Config {
+    model::Config {
+        filename: filename.to_string(),
+        xml: None,
+        trailer: Trailer {
+            generation: 0,
+            rest: trailer::KNOWN_REST.to_vec(),
+        },
+        memories: (0..model::NR_MEMORIES).map(default_memory).collect(),
+    }
+}
+
+/// Reset one menu of a memory to the default settings. Resetting NAME gives the default name.
+pub fn reset_menu(config: &mut model::Config, id: usize, menu_name: &str) -> Result<(), Rc500Error> {
+    let memory = config
+        .memories
+        .iter_mut()
+        .find(|memory| memory.id == id)
+        .ok_or_else(|| Rc500Error::invalid_value(format!("There is no memory {}", id + 1)))?;
+    let mut default = default_memory(id)
+        .menus
+        .into_iter()
+        .find(|menu| menu.name == menu_name)
+        .ok_or_else(|| Rc500Error::invalid_value(format!("There is no menu {}", menu_name)))?;
+    let menu = memory
+        .menus
+        .iter_mut()
+        .find(|menu| menu.name == menu_name)
+        .ok_or_else(|| Rc500Error::invalid_value(format!("Memory {} has no menu {}", id + 1, menu_name)))?;
+    if let (model::MenuContent::KeyValueMenu(old), model::MenuContent::KeyValueMenu(new)) =
+        (&menu.content, &mut default.content)
+    {
+        for setting in new.settings.iter_mut() {
+            let recording = old.settings.iter().find(|old| old.key == setting.key);
+            if let Some(old) = recording.filter(|old| RECORDING_KEYS.contains(&old.key.as_str())) {
+                setting.value = old.value;
+            }
+        }
+    }
+    *menu = default;
+    Ok(())
+}
+
+/// The tracks of the memory that have audio, counting from 1.
+fn recorded_tracks(memory: &model::Memory) -> Vec<usize> {
+    (1..=NR_TRACKS)
+        .filter(|track| model::get_setting(memory, &format!("TRACK{}", track), "WavStat") == Some(1))
+        .collect()
+}
+
+fn wave_folder(waves: &Path, id: usize, track: usize) -> PathBuf {
+    waves.join(format!("{:03}_{}", id + 1, track))
+}
+
+/// Carry out the plan on the config and, if given, stage the audio moves in the WAVE folder. The staged audio
+/// is put in place by [`commit_audio`], or dropped by [`discard_audio`].
+pub fn apply(config: &mut model::Config, plan: &Plan, waves: Option<&Path>) -> Result<(), Rc500Error> {
+    let find = |id: usize| {
+        config
+            .memories
+            .iter()
+            .find(|memory| memory.id == id)
+            .ok_or_else(|| Rc500Error::invalid_value(format!("There is no memory {}", id + 1)))
+    };
+    let mut new_memories = Vec::new();
+    let mut has_audio = false;
+    for (target, source) in plan {
+        let old = find(*target)?;
+        has_audio |= !recorded_tracks(old).is_empty();
+        let mut new = match source {
+            Source::Memory(id) | Source::Settings(id) => find(*id)?.clone(),
+            Source::Defaults => default_memory(*target),
+        };
+        has_audio |= !recorded_tracks(&new).is_empty();
+        new.id = *target;
+        if let Source::Settings(_) = source {
+            let name = old.menus.iter().find(|menu| menu.name == "NAME").cloned();
+            if let (Some(name), Some(menu)) = (name, new.menus.iter_mut().find(|menu| menu.name == "NAME")) {
+                *menu = name;
+            }
+        }
+        new_memories.push(new);
+    }
+    if has_audio {
+        let waves = waves.ok_or_else(|| {
+            Rc500Error::invalid_value(
+                "These memories have recorded audio, which is in the WAVE folder of the device. \
+                 Use --push to move the audio along with the memories on the device.",
+            )
+        })?;
+        stage_audio(config, plan, waves).inspect_err(|_| {
+            let _ = discard_audio(waves);
+        })?;
+    }
+    for new in new_memories {
+        if let Some(memory) = config.memories.iter_mut().find(|memory| memory.id == new.id) {
+            *memory = new;
+        }
+    }
+    Ok(())
+}
+
+fn staging_dir(waves: &Path) -> PathBuf {
+    waves.join(".rc500-staging")
+}
+
+fn audio_error(path: &Path, e: std::io::Error) -> Rc500Error {
+    Rc500Error::io(format!("Could not update {:?}: {}", path, e))
+}
+
+/// Put together the audio folders of the slots in the plan in a staging folder, so that swaps and moves do
+/// not overwrite audio that is still needed.
+fn stage_audio(config: &model::Config, plan: &Plan, waves: &Path) -> Result<(), Rc500Error> {
+    let staging = staging_dir(waves);
+    if staging.exists() {
+        fs::remove_dir_all(&staging).map_err(|e| audio_error(&staging, e))?;
+    }
+    fs::create_dir_all(&staging).map_err(|e| audio_error(&staging, e))?;
+    for (target, source) in plan {
+        let source_memory = match source {
+            Source::Memory(id) | Source::Settings(id) => config.memories.iter().find(|memory| memory.id == *id),
+            Source::Defaults => None,
+        };
+        if let Some(memory) = source_memory {
+            for track in recorded_tracks(memory) {
+                copy_dir(
+                    &wave_folder(waves, memory.id, track),
+                    &wave_folder(&staging, *target, track),
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Replace the audio folders of the slots in the plan with the ones staged by [`apply`].
+pub fn commit_audio(plan: &Plan, waves: &Path) -> Result<(), Rc500Error> {
+    let staging = staging_dir(waves);
+    if !staging.is_dir() {
+        return Ok(());
+    }
+    for (target, _) in plan {
+        for track in 1..=NR_TRACKS {
+            let folder = wave_folder(waves, *target, track);
+            if folder.exists() {
+                fs::remove_dir_all(&folder).map_err(|e| audio_error(&folder, e))?;
+            }
+            let staged = wave_folder(&staging, *target, track);
+            if staged.exists() {
+                fs::rename(&staged, &folder).map_err(|e| audio_error(&folder, e))?;
+            }
+        }
+    }
+    fs::remove_dir_all(&staging).map_err(|e| audio_error(&staging, e))
+}
+
+/// Drop the audio staged by [`apply`], leaving the WAVE folder as it was.
+pub fn discard_audio(waves: &Path) -> Result<(), Rc500Error> {
+    let staging = staging_dir(waves);
+    match staging.exists() {
+        true => fs::remove_dir_all(&staging).map_err(|e| audio_error(&staging, e)),
+        false => Ok(()),
+    }
+}
+
+fn copy_dir(from: &Path, to: &Path) -> Result<(), Rc500Error> {
+    let io_error = |e: std::io::Error| Rc500Error::io(format!("Could not copy {:?} to {:?}: {}", from, to, e));
+    fs::create_dir_all(to).map_err(io_error)?;
+    for entry in fs::read_dir(from).map_err(io_error)? {
+        let path = entry.map_err(io_error)?.path();
+        let target = to.join(path.file_name().unwrap_or_default());
+        if path.is_dir() {
+            copy_dir(&path, &target)?;
+        } else {
+            fs::copy(&path, &target).map_err(io_error)?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,49 @@
+//! The binary data that follows `</database>` in a memory file.
+//!
+//! The trailer has not been decoded. The RC500 keeps two copies of its memories, and the first byte of the
+//! trailer is taken to be a generation counter: it is increased on every save, and the copy with the higher
+//! one is loaded. What the RC500 does when the counter wraps around is not known. The remaining bytes
+//! (`21 20 20 0A` in the files we have seen) match neither a sum, xor, CRC-32 nor Adler-32 of the XML, and are
+//! kept as they are.
+
+/// The bytes after the generation counter in every file we have seen.
+pub const KNOWN_REST: &[u8] = b"!  \n";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Trailer {
+    /// Increased on every save, see the module documentation.
+    pub generation: u8,
+    /// The bytes after the generation counter, copied verbatim.
+    pub rest: Vec<u8>,
+}
+
+impl Trailer {
+    pub fn parse(bytes: &[u8]) -> Result<Trailer, String> {
+        match bytes.split_first() {
+            None => Err("The file ends without the binary data that should follow </database>".to_string()),
+            Some((generation, rest)) => Ok(Trailer {
+                generation: *generation,
+                rest: rest.to_vec(),
+            }),
+        }
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![self.generation];
+        bytes.extend_from_slice(&self.rest);
+        bytes
+    }
+
+    /// The trailer of the next save.
+    pub fn next(&self) -> Trailer {
+        Trailer {
+            generation: self.generation.wrapping_add(1),
+            rest: self.rest.clone(),
+        }
+    }
+
+    /// Whether this trailer has the higher generation counter. On a tie neither is newer.
+    pub fn is_newer_than(&self, other: &Trailer) -> bool {
+        self.generation > other.generation
+    }
+}
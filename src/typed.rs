@@ -0,0 +1,443 @@
+use crate::error::Rc500Error;
+use crate::model;
+
+/// Conversion between the typed representation of a setting and the raw number stored in the file.
+pub trait Value: Sized {
+    fn from_raw(raw: usize) -> Option<Self>;
+    fn to_raw(&self) -> usize;
+}
+
+impl Value for usize {
+    fn from_raw(raw: usize) -> Option<Self> {
+        Some(raw)
+    }
+    fn to_raw(&self) -> usize {
+        *self
+    }
+}
+
+/// Off/On settings are stored as 0/1.
+impl Value for bool {
+    fn from_raw(raw: usize) -> Option<Self> {
+        match raw {
+            0 => Some(false),
+            1 => Some(true),
+            _ => None,
+        }
+    }
+    fn to_raw(&self) -> usize {
+        *self as usize
+    }
+}
+
+macro_rules! value_enum {
+    ($(#[$meta:meta])* $name:ident { $($variant:ident = $raw:literal),* $(,)? }) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub enum $name {
+            $($variant),*
+        }
+
+        impl Value for $name {
+            fn from_raw(raw: usize) -> Option<Self> {
+                match raw {
+                    $($raw => Some($name::$variant),)*
+                    _ => None,
+                }
+            }
+            fn to_raw(&self) -> usize {
+                match self {
+                    $($name::$variant => $raw),*
+                }
+            }
+        }
+    };
+}
+
+macro_rules! typed_menu {
+    ($(#[$meta:meta])* $name:ident { $($field:ident: $type:ty = $key:literal),* $(,)? }) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub struct $name {
+            $(pub $field: $type),*
+        }
+
+        impl $name {
+            /// The keys of this menu, in the order in which they appear in the file.
+            pub const KEYS: &'static [&'static str] = &[$($key),*];
+
+            fn from_settings(menu: &str, settings: &[model::UntypedKeyValue]) -> Result<Self, Rc500Error> {
+                let keys: Vec<&str> = settings.iter().map(|setting| setting.key.as_str()).collect();
+                if keys != Self::KEYS {
+                    return Err(Rc500Error::invalid_value(format!(
+                        "Menu {} has keys {:?}, expected {:?}",
+                        menu,
+                        keys,
+                        Self::KEYS
+                    )));
+                }
+                let mut values = settings.iter().map(|setting| setting.value);
+                Ok($name {
+                    $($field: read_value(menu, $key, values.next().unwrap_or_default())?),*
+                })
+            }
+
+            fn to_settings(&self) -> Vec<model::UntypedKeyValue> {
+                vec![$(model::UntypedKeyValue {
+                    key: $key.to_string(),
+                    value: self.$field.to_raw(),
+                }),*]
+            }
+        }
+    };
+}
+
+fn read_value<T: Value>(menu: &str, key: &str, raw: usize) -> Result<T, Rc500Error> {
+    T::from_raw(raw)
+        .ok_or_else(|| Rc500Error::invalid_value(format!("Value {} is not valid for {}/{}", raw, menu, key)))
+}
+
+value_enum!(StartMode {
+    Immediate = 0,
+    FadeIn = 1,
+});
+
+value_enum!(StopMode {
+    Immediate = 0,
+    FadeOut = 1,
+    LoopEnd = 2,
+});
+
+value_enum!(Input {
+    All = 0,
+    MicIn = 1,
+    InstIn = 2,
+    InstInA = 3,
+    InstInB = 4,
+    MicInst = 5,
+});
+
+value_enum!(Output {
+    All = 0,
+    OutA = 1,
+    OutB = 2,
+});
+
+value_enum!(DubMode {
+    Overdub = 0,
+    Replace = 1,
+});
+
+value_enum!(RecAction {
+    RecordDub = 0,
+    RecordPlay = 1,
+});
+
+value_enum!(RecQuantize {
+    Off = 0,
+    Measure = 1,
+});
+
+value_enum!(AutoRecSource {
+    All = 0,
+    MicIn = 1,
+    Inst = 2,
+    InstA = 3,
+    InstB = 4,
+});
+
+value_enum!(PlayMode {
+    Multi = 0,
+    Single = 1,
+});
+
+value_enum!(SingleChange {
+    Immediate = 0,
+    LoopEnd = 1,
+});
+
+value_enum!(AllStart {
+    All = 0,
+    Track1 = 1,
+    Track2 = 2,
+});
+
+value_enum!(TrackChain {
+    Parallel = 0,
+    Series = 1,
+});
+
+value_enum!(CurrentTrack {
+    Track1 = 0,
+    Track2 = 1,
+});
+
+value_enum!(
+    /// Note that the device stores Yes as 0.
+    AllTrackSelect {
+        Yes = 0,
+        No = 1,
+    }
+);
+
+value_enum!(Variation { A = 0, B = 1 });
+
+value_enum!(VariationChange {
+    Measure = 0,
+    LoopEnd = 1,
+});
+
+value_enum!(Kit {
+    Studio = 0,
+    Live = 1,
+    Light = 2,
+    Heavy = 3,
+    Rock = 4,
+    Metal = 5,
+    Jazz = 6,
+    Brush = 7,
+    Cajon = 8,
+    DrumAndBass = 9,
+    RnB = 10,
+    Dance = 11,
+    Techno = 12,
+    DanceBeats = 13,
+    HipHop = 14,
+    Kit808And909 = 15,
+});
+
+value_enum!(Count {
+    Off = 0,
+    OneMeasure = 1,
+});
+
+value_enum!(RhythmStart {
+    LoopStart = 0,
+    RecordEnd = 1,
+    BeforeLoop = 2,
+});
+
+value_enum!(RhythmStop {
+    Never = 0,
+    LoopStop = 1,
+    RecordEnd = 2,
+});
+
+value_enum!(SourceMode {
+    Moment = 0,
+    Toggle = 1,
+});
+
+// The loop FX types in the order of the parameter guide, which the default in the schema relies on.
+value_enum!(FxType {
+    Scatter1 = 0,
+    Scatter2 = 1,
+    Scatter3 = 2,
+    Scatter4 = 3,
+    Repeat1 = 4,
+    Repeat2 = 5,
+    Repeat3 = 6,
+    Shift1 = 7,
+    Shift2 = 8,
+    VinylFlick = 9,
+});
+
+typed_menu!(Track {
+    reverse: bool = "Rev",
+    play_level: usize = "PlyLvl",
+    pan: usize = "Pan",
+    one_shot: bool = "One",
+    loop_fx: bool = "LoopFx",
+    start: StartMode = "StrtMod",
+    stop: StopMode = "StpMod",
+    measure: usize = "Measure",
+    loop_sync: bool = "LoopSync",
+    tempo_sync: bool = "TempoSync",
+    input: Input = "Input",
+    output: Output = "Output",
+    measure_mode: usize = "MeasMod",
+    measure_length: usize = "MeasLen",
+    measure_beat_loop: usize = "MeasBtLp",
+    recorded_tempo: usize = "RecTmp",
+    has_recording: bool = "WavStat",
+    recording_length: usize = "WavLen",
+});
+
+typed_menu!(Master {
+    tempo: usize = "Tempo",
+    dub_mode: DubMode = "DubMode",
+    rec_action: RecAction = "RecAction",
+    rec_quantize: RecQuantize = "RecQuantize",
+    auto_rec: bool = "AutoRec",
+    auto_rec_sensitivity: usize = "AutoRecSens",
+    auto_rec_source: AutoRecSource = "AutoRecSrc",
+    play_mode: PlayMode = "PlayMode",
+    single_change: SingleChange = "SinglPlayeChange",
+    fade_time: usize = "FadeTime",
+    all_start: AllStart = "AllStart",
+    track_chain: TrackChain = "TrackChain",
+    current_track: CurrentTrack = "CurrentTrack",
+    all_track_select: AllTrackSelect = "AllTrackSel",
+    level: usize = "Level",
+    loop_mode: usize = "LpMod",
+    loop_length: usize = "LpLen",
+    track_mode: usize = "TrkMod",
+    sync: usize = "Sync",
+});
+
+typed_menu!(LoopFx {
+    enabled: bool = "Sw",
+    fx_type: FxType = "FxType",
+    repeat_length: usize = "RepeatLength",
+    shift: usize = "ShiftShift",
+    scatter_length: usize = "ScatterLength",
+    vinyl_flick: usize = "VinylFlickFlick",
+});
+
+typed_menu!(Rhythm {
+    level: usize = "Level",
+    reverb: usize = "Reverb",
+    pattern: usize = "Pattern",
+    variation: Variation = "Variation",
+    variation_change: VariationChange = "VariationChange",
+    kit: Kit = "Kit",
+    beat: usize = "Beat",
+    fill: bool = "Fill",
+    part1: bool = "Part1",
+    part2: bool = "Part2",
+    part3: bool = "Part3",
+    part4: bool = "Part4",
+    rec_count: Count = "RecCount",
+    play_count: Count = "PlayCount",
+    start: RhythmStart = "Start",
+    stop: RhythmStop = "Stop",
+    tone_low: usize = "ToneLow",
+    tone_high: usize = "ToneHigh",
+    state: usize = "State",
+});
+
+typed_menu!(
+    /// The functions are indices into the labels of the schema.
+    Ctl {
+        pedal1: usize = "Pedal1",
+        pedal2: usize = "Pedal2",
+        pedal3: usize = "Pedal3",
+        ctl1: usize = "Ctl1",
+        ctl2: usize = "Ctl2",
+        exp: usize = "Exp",
+    }
+);
+
+typed_menu!(
+    /// The source and target are indices into the labels of the schema.
+    Assign {
+        enabled: bool = "Sw",
+        source: usize = "Source",
+        source_mode: SourceMode = "SourceMode",
+        target: usize = "Target",
+        target_min: usize = "TargetMin",
+        target_max: usize = "TargetMax",
+    }
+);
+
+pub const NR_ASSIGNS: usize = 8;
+
+/// A memory with every menu and setting in its own field, for code that wants to work with the settings
+/// without looking them up by name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Memory {
+    pub id: usize,
+    pub name: String,
+    pub track1: Track,
+    pub track2: Track,
+    pub master: Master,
+    pub loop_fx: LoopFx,
+    pub rhythm: Rhythm,
+    pub ctl: Ctl,
+    pub assigns: [Assign; NR_ASSIGNS],
+}
+
+/// The menus of a memory, in the order in which they appear in the file.
+pub fn menu_names() -> Vec<String> {
+    let mut names: Vec<String> = ["NAME", "TRACK1", "TRACK2", "MASTER", "LOOPFX", "RHYTHM", "CTL"]
+        .iter()
+        .map(|name| name.to_string())
+        .collect();
+    names.extend((1..=NR_ASSIGNS).map(|i| format!("ASSIGN{}", i)));
+    names
+}
+
+impl TryFrom<&model::Memory> for Memory {
+    type Error = Rc500Error;
+
+    /// Fails if the memory does not have exactly the expected menus and keys in the expected order, so that
+    /// converting back yields the same memory.
+    fn try_from(memory: &model::Memory) -> Result<Self, Self::Error> {
+        let names: Vec<&str> = memory.menus.iter().map(|menu| menu.name.as_str()).collect();
+        if names != menu_names() {
+            return Err(Rc500Error::invalid_value(format!(
+                "Memory {} has unexpected menus {:?}",
+                memory.id + 1,
+                names
+            )));
+        }
+        let settings = |i: usize| -> Result<&[model::UntypedKeyValue], Rc500Error> {
+            match &memory.menus[i].content {
+                model::MenuContent::KeyValueMenu(menu) => Ok(&menu.settings),
+                model::MenuContent::StringValueMenu(_) => {
+                    Err(Rc500Error::invalid_value(format!("Menu {} has no settings", names[i])))
+                }
+            }
+        };
+        let name = match &memory.menus[0].content {
+            model::MenuContent::StringValueMenu(menu) => menu.value.clone(),
+            model::MenuContent::KeyValueMenu(_) => return Err(Rc500Error::invalid_value("Menu NAME is not a string")),
+        };
+        let mut assigns: Vec<Assign> = Vec::new();
+        for i in 0..NR_ASSIGNS {
+            assigns.push(Assign::from_settings(names[7 + i], settings(7 + i)?)?);
+        }
+        Ok(Memory {
+            id: memory.id,
+            name,
+            track1: Track::from_settings(names[1], settings(1)?)?,
+            track2: Track::from_settings(names[2], settings(2)?)?,
+            master: Master::from_settings(names[3], settings(3)?)?,
+            loop_fx: LoopFx::from_settings(names[4], settings(4)?)?,
+            rhythm: Rhythm::from_settings(names[5], settings(5)?)?,
+            ctl: Ctl::from_settings(names[6], settings(6)?)?,
+            assigns: assigns
+                .try_into()
+                .map_err(|_| Rc500Error::invalid_value("Wrong number of assigns"))?,
+        })
+    }
+}
+
+impl From<&Memory> for model::Memory {
+    fn from(memory: &Memory) -> Self {
+        let mut contents = vec![
+            model::MenuContent::StringValueMenu(model::StringValueMenu {
+                value: memory.name.clone(),
+            }),
+            key_value_menu(memory.track1.to_settings()),
+            key_value_menu(memory.track2.to_settings()),
+            key_value_menu(memory.master.to_settings()),
+            key_value_menu(memory.loop_fx.to_settings()),
+            key_value_menu(memory.rhythm.to_settings()),
+            key_value_menu(memory.ctl.to_settings()),
+        ];
+        contents.extend(memory.assigns.iter().map(|assign| key_value_menu(assign.to_settings())));
+        model::Memory {
+            id: memory.id,
+            menus: menu_names()
+                .into_iter()
+                .zip(contents)
+                .map(|(name, content)| model::Menu { name, content })
+                .collect(),
+        }
+    }
+}
+
+fn key_value_menu(settings: Vec<model::UntypedKeyValue>) -> model::MenuContent {
+    model::MenuContent::KeyValueMenu(model::UntypedMenu { settings })
+}
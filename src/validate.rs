@@ -0,0 +1,249 @@
+//! Checks that a memory file is one the RC500 will accept.
+//!
+//! The device does not report invalid files: it silently falls back to its other copy. So before pushing,
+//! every file is checked against everything we know about the format.
+
+use crate::error::Rc500Error;
+use crate::io;
+use crate::model;
+use crate::reader;
+use crate::schema;
+use crate::typed;
+use crate::writer;
+use std::fmt;
+use std::path::Path;
+
+/// A problem with a config, located by memory/menu/key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Issue {
+    pub path: String,
+    pub message: String,
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// One issue per line.
+pub fn format_issues(issues: &[Issue]) -> String {
+    issues
+        .iter()
+        .map(|issue| issue.to_string())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn issue(path: String, message: String) -> Issue {
+    Issue { path, message }
+}
+
+/// Check both memory files in the working directory, and the system files if there are any. Paths start
+/// with the name of the file.
+pub fn check_dir(working_dir: &str) -> Vec<Issue> {
+    let paths = io::config_file_paths(working_dir);
+    let system_paths = io::system_file_paths(working_dir);
+    let mut issues = Vec::new();
+    let mut add = |name: &str, file_issues: Vec<Issue>| {
+        issues.extend(file_issues.into_iter().map(|issue| Issue {
+            path: format!("{}/{}", name, issue.path),
+            message: issue.message,
+        }))
+    };
+    for (name, path) in io::MEMORY_FILES.iter().zip([&paths.0, &paths.1]) {
+        add(name, check_file(path));
+    }
+    for (name, path) in io::SYSTEM_FILES.iter().zip([&system_paths.0, &system_paths.1]) {
+        if path.is_file() {
+            add(name, check_system_file(path));
+        }
+    }
+    issues
+}
+
+/// Read and check the memory file at the given path. A file that cannot be read at all is a single issue. The
+/// file is pushed as it is, so its names are checked as they are in the file.
+pub fn check_file(path: &Path) -> Vec<Issue> {
+    match reader::read(path) {
+        Err(e) => unreadable(e),
+        Ok(config) => check_with_names(&config, &name_codes(&config)),
+    }
+}
+
+/// Read the system file at the given path, like [`check_file`]. Which menus, keys and values the RC500
+/// expects in a system file is not known from a real one, so only whether it can be read is checked.
+pub fn check_system_file(path: &Path) -> Vec<Issue> {
+    match reader::read_system(path) {
+        Err(e) => unreadable(e),
+        Ok(_) => Vec::new(),
+    }
+}
+
+fn unreadable(error: Rc500Error) -> Vec<Issue> {
+    match error {
+        Rc500Error::Parse {
+            message,
+            location: Some(location),
+            ..
+        } => vec![issue(
+            location.path.unwrap_or_else(|| "database".to_string()),
+            format!("{} (line {}, column {})", message, location.line, location.column),
+        )],
+        e => vec![issue("database".to_string(), e.message().to_string())],
+    }
+}
+
+/// Check the memories, menus, keys, values and names of the config, as it will be written. The bytes after the
+/// generation counter of the trailer are not understood, so they are not checked.
+pub fn check(config: &model::Config) -> Vec<Issue> {
+    check_with_names(config, &written_name_codes(config))
+}
+
+/// Check the config with the character codes of its names, which the model does not keep.
+fn check_with_names(config: &model::Config, names: &[Vec<usize>]) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    if config.memories.len() != model::NR_MEMORIES {
+        issues.push(issue(
+            "database".to_string(),
+            format!(
+                "Found {} memories, expected {}",
+                config.memories.len(),
+                model::NR_MEMORIES
+            ),
+        ));
+    }
+    for (i, memory) in config.memories.iter().enumerate() {
+        let path = format!("Memory {}", i + 1);
+        if memory.id != i {
+            issues.push(issue(path.clone(), format!("Has id {}, expected {}", memory.id, i)));
+        }
+        check_menus(&path, memory, &mut issues);
+        let codes = match names.get(i) {
+            Some(codes) => codes.clone(),
+            None => format!(
+                "{:<width$}",
+                model::get_memory_name(memory),
+                width = model::STRING_MAX_WIDTH
+            )
+            .chars()
+            .map(|c| c as usize)
+            .collect(),
+        };
+        check_name(&format!("{}/NAME", path), &codes, &mut issues);
+    }
+    issues
+}
+
+fn check_bounds(path: &str, param: &schema::Param, value: usize, issues: &mut Vec<Issue>) {
+    if !param.is_in_bounds(value) {
+        issues.push(issue(
+            path.to_string(),
+            format!("Value {} is outside of the range {}..={}", value, param.min, param.max),
+        ));
+    }
+}
+
+fn check_menus(path: &str, memory: &model::Memory, issues: &mut Vec<Issue>) {
+    let expected = typed::menu_names();
+    let actual: Vec<&str> = memory.menus.iter().map(|menu| menu.name.as_str()).collect();
+    check_order(path, "menu", &expected, &actual, issues);
+    for menu in &memory.menus {
+        let path = format!("{}/{}", path, menu.name);
+        let (params, settings) = match (&menu.content, schema::menu_params(&menu.name)) {
+            (model::MenuContent::KeyValueMenu(settings), Some(params)) => (params, &settings.settings),
+            (model::MenuContent::KeyValueMenu(_), None) => continue,
+            (model::MenuContent::StringValueMenu(_), _) => continue,
+        };
+        let expected: Vec<String> = params.keys().map(|key| key.to_string()).collect();
+        let actual: Vec<&str> = settings.iter().map(|setting| setting.key.as_str()).collect();
+        check_order(&path, "key", &expected, &actual, issues);
+        for setting in settings {
+            if let Some(param) = params.get(setting.key.as_str()) {
+                check_bounds(&format!("{}/{}", path, setting.key), param, setting.value, issues);
+            }
+        }
+    }
+}
+
+/// Report missing and unknown entries, or a wrong order if all entries are there.
+fn check_order(path: &str, kind: &str, expected: &[String], actual: &[&str], issues: &mut Vec<Issue>) {
+    let mut complete = true;
+    for name in expected {
+        if !actual.contains(&name.as_str()) {
+            issues.push(issue(path.to_string(), format!("Missing {} {}", kind, name)));
+            complete = false;
+        }
+    }
+    for name in actual {
+        if !expected.iter().any(|e| e == name) {
+            issues.push(issue(format!("{}/{}", path, name), format!("Unknown {}", kind)));
+            complete = false;
+        }
+    }
+    if complete && expected.iter().map(|e| e.as_str()).ne(actual.iter().copied()) {
+        issues.push(issue(
+            path.to_string(),
+            format!("The {}s are not in the order {}", kind, expected.join(", ")),
+        ));
+    }
+}
+
+fn check_name(path: &str, codes: &[usize], issues: &mut Vec<Issue>) {
+    if codes.len() != model::STRING_MAX_WIDTH {
+        issues.push(issue(
+            path.to_string(),
+            format!(
+                "Has {} characters, expected exactly {}",
+                codes.len(),
+                model::STRING_MAX_WIDTH
+            ),
+        ));
+    }
+    for (i, code) in codes.iter().enumerate() {
+        let valid = u8::try_from(*code).is_ok_and(|c| model::is_name_char(c as char));
+        if !valid {
+            issues.push(issue(
+                format!("{}/C{:02}", path, i + 1),
+                format!("Character code {} cannot be shown by the RC500", code),
+            ));
+        }
+    }
+}
+
+/// The raw character codes of the NAME of every memory, as the writer will put them in the file. The XML a
+/// config carries can be that of another file, like the device's in a partial push, so it is not used.
+fn written_name_codes(config: &model::Config) -> Vec<Vec<usize>> {
+    match reader::parse(&writer::render(config), &config.filename) {
+        Ok(written) => name_codes(&written),
+        Err(_) => Vec::new(),
+    }
+}
+
+/// The raw character codes of the NAME of every memory, as they are in the XML of the config. The model trims
+/// the padding, so only the XML tells whether a name in a file has exactly 12 characters.
+fn name_codes(config: &model::Config) -> Vec<Vec<usize>> {
+    let doc = match config.xml.as_deref().map(roxmltree::Document::parse) {
+        Some(Ok(doc)) => doc,
+        _ => return Vec::new(),
+    };
+    let database = match doc.root().first_child() {
+        Some(database) => database,
+        None => return Vec::new(),
+    };
+    database
+        .children()
+        .filter(|mem| mem.is_element())
+        .map(|mem| {
+            mem.children()
+                .find(|menu| menu.has_tag_name("NAME"))
+                .map(|name| {
+                    name.children()
+                        .filter(|c| c.is_element())
+                        .map(|c| c.text().and_then(|t| t.parse().ok()).unwrap_or(usize::MAX))
+                        .collect()
+                })
+                .unwrap_or_default()
+        })
+        .collect()
+}
@@ -1,50 +1,173 @@
+use crate::error::Rc500Error;
 use crate::model;
-use std::fs::File;
-use std::io;
-use std::io::Write;
+use crate::schema;
+use std::fmt::Write as _;
+use std::ops::Range;
 
-pub fn write(filename: &str, config: &model::Config) -> Result<(), String> {
-    _write(filename, config).map_err(|e| format!("Error while writing: {}", e))
+pub fn write(filename: &str, config: &model::Config) -> Result<(), Rc500Error> {
+    schema::check_config(config)?;
+    std::fs::write(filename, render(config)).map_err(|e| Rc500Error::io(format!("Error while writing: {}", e)))
 }
 
-fn _write(filename: &str, config: &model::Config) -> io::Result<()> {
-    let mut file = File::create(filename)?;
-    writeln!(&mut file, r#"<?xml version="1.0" encoding="utf-8"?>"#)?;
-    writeln!(&mut file, r#"<database name="RC-500" revision="0">"#)?;
+/// The exact bytes that `write` puts on disk.
+///
+/// If the config was read from a file, its values are patched into the original XML, so that the header,
+/// whitespace, comments and unknown elements survive unchanged. Reading a file and rendering it without
+/// changes gives back the same bytes. Only when the original structure no longer matches the config, the XML
+/// is generated from scratch.
+pub fn render(config: &model::Config) -> Vec<u8> {
+    let xml = match config.xml.as_deref().and_then(|xml| patch(xml, config)) {
+        Some(xml) => xml,
+        None => generate(config),
+    };
+    let mut bytes = xml.into_bytes();
+    bytes.extend(config.trailer.to_bytes());
+    bytes
+}
 
-    for mem in &config.memories {
-        writeln!(&mut file, "<mem id=\"{}\">", mem.id)?;
-        for menu in &mem.menus {
-            writeln!(&mut file, "<{}>", menu.name)?;
-            match &menu.content {
-                model::MenuContent::StringValueMenu(menu) => write_string_menu(&mut file, menu)?,
-                model::MenuContent::KeyValueMenu(menu) => write_key_value_menu(&mut file, menu)?,
-            };
-            writeln!(&mut file, "</{}>", menu.name)?;
+pub fn write_system(filename: &str, system: &model::SystemConfig) -> Result<(), Rc500Error> {
+    std::fs::write(filename, render_system(system)).map_err(|e| Rc500Error::io(format!("Error while writing: {}", e)))
+}
+
+/// The exact bytes that `write_system` puts on disk, preserving the original XML like `render` does.
+pub fn render_system(system: &model::SystemConfig) -> Vec<u8> {
+    let xml = match system.xml.as_deref().and_then(|xml| patch_system(xml, system)) {
+        Some(xml) => xml,
+        None => generate_system(system),
+    };
+    let mut bytes = xml.into_bytes();
+    bytes.extend(system.trailer.to_bytes());
+    bytes
+}
+
+fn patch_system(xml: &str, system: &model::SystemConfig) -> Option<String> {
+    let doc = roxmltree::Document::parse(xml).ok()?;
+    let database = doc.root().first_child()?;
+    let sys_node = database.children().find(|c| c.is_element())?;
+    Some(apply(xml, menu_replacements(&sys_node, &system.menus)?))
+}
+
+fn generate_system(system: &model::SystemConfig) -> String {
+    let mut xml = String::new();
+    // Writing to a String cannot fail
+    let _ = generate_system_into(&mut xml, system);
+    xml
+}
+
+fn generate_system_into(xml: &mut String, system: &model::SystemConfig) -> std::fmt::Result {
+    writeln!(xml, r#"<?xml version="1.0" encoding="utf-8"?>"#)?;
+    writeln!(xml, r#"<database name="RC-500" revision="0">"#)?;
+    writeln!(xml, "<sys>")?;
+    generate_menus(xml, &system.menus)?;
+    writeln!(xml, "</sys>")?;
+    writeln!(xml, r#"</database>"#)
+}
+
+fn patch(xml: &str, config: &model::Config) -> Option<String> {
+    let doc = roxmltree::Document::parse(xml).ok()?;
+    let database = doc.root().first_child()?;
+    let mem_nodes: Vec<roxmltree::Node> = database.children().filter(|c| c.is_element()).collect();
+    if mem_nodes.len() != config.memories.len() {
+        return None;
+    }
+    let mut replacements: Vec<(Range<usize>, String)> = Vec::new();
+    for (mem_node, mem) in mem_nodes.iter().zip(&config.memories) {
+        let id = mem_node.attributes().iter().find(|attr| attr.name() == "id")?;
+        replacements.push((id.value_range(), mem.id.to_string()));
+        replacements.extend(menu_replacements(mem_node, &mem.menus)?);
+    }
+    Some(apply(xml, replacements))
+}
+
+/// The ranges in the XML of the values of the settings of the menus, with their new value.
+fn menu_replacements(parent: &roxmltree::Node, menus: &[model::Menu]) -> Option<Vec<(Range<usize>, String)>> {
+    let mut replacements: Vec<(Range<usize>, String)> = Vec::new();
+    let menu_nodes: Vec<roxmltree::Node> = parent.children().filter(|c| c.is_element()).collect();
+    if menu_nodes.len() != menus.len() {
+        return None;
+    }
+    for (menu_node, menu) in menu_nodes.iter().zip(menus) {
+        if menu_node.tag_name().name() != menu.name {
+            return None;
+        }
+        let settings = menu_settings(menu);
+        let setting_nodes: Vec<roxmltree::Node> = menu_node.children().filter(|c| c.is_element()).collect();
+        if setting_nodes.len() != settings.len() {
+            return None;
         }
-        writeln!(&mut file, "</mem>")?;
+        for (setting_node, (key, value)) in setting_nodes.iter().zip(settings) {
+            let text_node = setting_node.first_child().filter(|c| c.is_text())?;
+            if setting_node.tag_name().name() != key || text_node.next_sibling().is_some() {
+                return None;
+            }
+            replacements.push((text_node.range(), value));
+        }
+    }
+    Some(replacements)
+}
+
+fn apply(xml: &str, replacements: Vec<(Range<usize>, String)>) -> String {
+    let mut result = String::with_capacity(xml.len());
+    let mut end = 0;
+    for (range, value) in replacements {
+        result.push_str(&xml[end..range.start]);
+        result.push_str(&value);
+        end = range.end;
     }
+    result.push_str(&xml[end..]);
+    result
+}
 
-    writeln!(&mut file, r#"</database>"#)?;
-    file.write_all(&config.suffix)?;
-    Ok(())
+fn generate(config: &model::Config) -> String {
+    let mut xml = String::new();
+    // Writing to a String cannot fail
+    let _ = generate_into(&mut xml, config);
+    xml
 }
 
-fn write_string_menu(file: &mut File, menu: &model::StringValueMenu) -> io::Result<()> {
-    let mut i: i32 = 1;
-    let mut value = menu.value.clone();
-    value.truncate(model::STRING_MAX_WIDTH);
-    value = format!("{:12}", value);
-    for c in value.chars() {
-        writeln!(file, "\t<C{:02}>{}</C{:02}>", i, c as u8, i)?;
-        i += 1;
+fn generate_into(xml: &mut String, config: &model::Config) -> std::fmt::Result {
+    writeln!(xml, r#"<?xml version="1.0" encoding="utf-8"?>"#)?;
+    writeln!(xml, r#"<database name="RC-500" revision="0">"#)?;
+
+    for mem in &config.memories {
+        writeln!(xml, "<mem id=\"{}\">", mem.id)?;
+        generate_menus(xml, &mem.menus)?;
+        writeln!(xml, "</mem>")?;
     }
-    Ok(())
+
+    writeln!(xml, r#"</database>"#)
 }
 
-fn write_key_value_menu(file: &mut File, menu: &model::UntypedMenu) -> io::Result<()> {
-    for setting in &menu.settings {
-        writeln!(file, "\t<{}>{}</{}>", setting.key, setting.value, setting.key)?;
+fn generate_menus(xml: &mut String, menus: &[model::Menu]) -> std::fmt::Result {
+    for menu in menus {
+        writeln!(xml, "<{}>", menu.name)?;
+        for (key, value) in menu_settings(menu) {
+            writeln!(xml, "\t<{}>{}</{}>", key, value, key)?;
+        }
+        writeln!(xml, "</{}>", menu.name)?;
     }
     Ok(())
 }
+
+/// The keys and values of the menu as they are written to the file.
+fn menu_settings(menu: &model::Menu) -> Vec<(String, String)> {
+    match &menu.content {
+        model::MenuContent::StringValueMenu(menu) => string_menu_settings(menu),
+        model::MenuContent::KeyValueMenu(menu) => menu
+            .settings
+            .iter()
+            .map(|setting| (setting.key.clone(), setting.value.to_string()))
+            .collect(),
+    }
+}
+
+/// A string is stored as one setting per character, padded with spaces
+fn string_menu_settings(menu: &model::StringValueMenu) -> Vec<(String, String)> {
+    let mut value = menu.value.clone();
+    value.truncate(model::STRING_MAX_WIDTH);
+    value = format!("{:12}", value);
+    (1..)
+        .zip(value.chars())
+        .map(|(i, c)| (format!("C{:02}", i), (c as u8).to_string()))
+        .collect()
+}
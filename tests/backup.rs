@@ -0,0 +1,39 @@
+mod common;
+
+use boss_rc500::{backup, SettingPath};
+
+#[test]
+fn unchanged_files_are_not_snapshotted_twice() {
+    let temp = common::working_dir("backup-unchanged");
+    let dir = temp.name();
+    assert!(backup::create(&dir, "manual").unwrap().is_some());
+    assert!(backup::create(&dir, "manual").unwrap().is_none());
+    assert_eq!(backup::list(&dir).unwrap().len(), 1);
+}
+
+#[test]
+fn snapshots_show_changes_and_can_be_restored() {
+    let temp = common::working_dir("backup-restore");
+    let dir = temp.name();
+    let path: SettingPath = "1/TRACK1/PlyLvl".parse().unwrap();
+    let first = backup::create(&dir, "manual").unwrap().unwrap();
+    let old = boss_rc500::get_value(&boss_rc500::load(&dir).unwrap(), &path, true).unwrap();
+
+    let mut config = boss_rc500::load(&dir).unwrap();
+    boss_rc500::set_value(&mut config, &path, "3").unwrap();
+    backup::around(&dir, "set", || boss_rc500::save(&mut config)).unwrap();
+
+    let snapshots = backup::list(&dir).unwrap();
+    assert_eq!(snapshots.len(), 2);
+    assert_eq!(snapshots[1].reason, "set");
+    let changes = backup::changes(Some(&snapshots[0]), &snapshots[1]).unwrap();
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].new.as_deref(), Some("3"));
+
+    backup::restore(&dir, &first).unwrap();
+    assert_eq!(
+        boss_rc500::get_value(&boss_rc500::load(&dir).unwrap(), &path, true).unwrap(),
+        old
+    );
+    assert!(backup::restore(&dir, "19990101-000000").is_err());
+}
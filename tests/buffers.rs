@@ -0,0 +1,51 @@
+mod common;
+
+use boss_rc500::buffers::{self, Slot};
+use std::path::Path;
+
+fn generation(dir: &Path, name: &str) -> u8 {
+    boss_rc500::load_file(&dir.join(name)).unwrap().trailer.generation
+}
+
+#[test]
+fn saving_alternates_slots_and_keeps_them_consistent() {
+    let temp = common::working_dir("buffers-alternate");
+    let dir = temp.path();
+    let dir_name = temp.name();
+
+    let mut config = boss_rc500::load(&dir_name).unwrap();
+    assert!(config.filename.ends_with("MEMORY1.RC0"));
+    boss_rc500::set_setting(&mut config, 0, "TRACK1", "PlyLvl", 57).unwrap();
+    boss_rc500::save(&mut config).unwrap();
+    assert!(config.filename.ends_with("MEMORY2.RC0"));
+    assert_eq!((generation(dir, "MEMORY1.RC0"), generation(dir, "MEMORY2.RC0")), (0, 1));
+
+    let status = buffers::status(dir, ["MEMORY1.RC0", "MEMORY2.RC0"]);
+    assert_eq!(status.active(), Some(Slot::B));
+    assert!(status.consistent);
+    assert_eq!(
+        status.reason(),
+        "MEMORY2.RC0 is active because its generation 1 is newer than 0"
+    );
+
+    boss_rc500::save(&mut config).unwrap();
+    assert!(config.filename.ends_with("MEMORY1.RC0"));
+    assert_eq!((generation(dir, "MEMORY1.RC0"), generation(dir, "MEMORY2.RC0")), (2, 1));
+
+    for name in ["MEMORY1.RC0", "MEMORY2.RC0"] {
+        let saved = boss_rc500::load_file(&dir.join(name)).unwrap();
+        assert_eq!(boss_rc500::get_setting(&saved, 0, "TRACK1", "PlyLvl"), Some(57));
+    }
+}
+
+#[test]
+fn a_tie_is_won_by_the_first_slot() {
+    let temp = common::working_dir("buffers-tie");
+    let dir = temp.path();
+    let status = buffers::status(dir, ["MEMORY1.RC0", "MEMORY2.RC0"]);
+    assert_eq!(status.active(), Some(Slot::A));
+    assert_eq!(
+        status.reason(),
+        "MEMORY1.RC0 is active because both slots have generation 0 and the first one wins a tie"
+    );
+}
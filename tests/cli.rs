@@ -0,0 +1,57 @@
+mod common;
+
+use std::process::Command;
+
+/// Run rc500 with --json in the working directory, and give the exit code and the error object it printed.
+fn run_json(working_dir: &str, args: &[&str]) -> (i32, serde_json::Value) {
+    let output = Command::new(env!("CARGO_BIN_EXE_rc500"))
+        .args(["--working-dir", working_dir, "--json"])
+        .args(args)
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let error = serde_json::from_str(stdout.lines().last().unwrap_or_default()).unwrap_or_default();
+    (output.status.code().unwrap(), error)
+}
+
+#[test]
+fn errors_exit_with_the_code_of_their_kind() {
+    let temp = common::TempDir::new("cli");
+    let dir = temp.name();
+    assert_eq!(run_json(&dir, &["init"]).0, 0);
+
+    let (code, error) = run_json(&dir, &["set", "1/TRACK1/PlyLvl", "999"]);
+    assert_eq!(code, 5);
+    assert_eq!(error["kind"], "invalid_value");
+    assert_eq!(error["exit_code"], 5);
+    assert!(
+        error["message"].as_str().unwrap().starts_with("1/TRACK1/PlyLvl: "),
+        "{}",
+        error
+    );
+
+    let (code, error) = run_json(&dir, &["init"]);
+    assert_eq!((code, &error["kind"]), (6, &"io".into()));
+
+    let (code, error) = run_json(&dir, &["--device-root", &dir, "pull"]);
+    assert_eq!((code, &error["kind"]), (3, &"no_device".into()));
+
+    std::fs::write(temp.join("MEMORY1.RC0"), "<database>").unwrap();
+    let (code, error) = run_json(&dir, &["get", "1/TRACK1/PlyLvl"]);
+    assert_eq!((code, &error["kind"]), (4, &"parse".into()));
+    assert!(error["file"].as_str().unwrap().ends_with("MEMORY1.RC0"), "{}", error);
+}
+
+#[test]
+fn lint_errors_fail_in_json_mode_too() {
+    let temp = common::TempDir::new("cli-lint");
+    let dir = temp.name();
+    assert_eq!(run_json(&dir, &["init"]).0, 0);
+    assert_eq!(run_json(&dir, &["lint"]).0, 0);
+
+    assert_eq!(run_json(&dir, &["set", "1/MASTER/AutoRec", "1"]).0, 0);
+    assert_eq!(run_json(&dir, &["set", "1/MASTER/AutoRecSens", "0"]).0, 0);
+    let (code, error) = run_json(&dir, &["lint"]);
+    assert_eq!(code, 5);
+    assert_eq!(error["message"], "Found 1 problem with severity error");
+}
@@ -0,0 +1,30 @@
+mod common;
+
+use boss_rc500::diff;
+use common::small_config;
+
+#[test]
+fn changes_are_shown_by_display_name() {
+    let old = small_config();
+    let mut new = small_config();
+    assert!(diff::diff(&old, &new).is_empty());
+
+    boss_rc500::set_setting(&mut new, 1, "TRACK1", "StpMod", 1).unwrap();
+    boss_rc500::set_setting(&mut new, 1, "MASTER", "Tempo", 1250).unwrap();
+    boss_rc500::set_name(&mut new, 1, "INTRO").unwrap();
+    let lines: Vec<String> = diff::diff(&old, &new).iter().map(|c| c.to_string()).collect();
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].starts_with("Memory 2 (INTRO) / NAME / Name: "));
+    assert_eq!(lines[1], "Memory 2 (INTRO) / TRACK1 / Stop: Immediate -> Fade Out");
+    assert!(lines[2].starts_with("Memory 2 (INTRO) / MASTER / Tempo: "));
+}
+
+#[test]
+fn missing_memories_are_changes() {
+    let old = small_config();
+    let mut new = small_config();
+    new.memories.pop();
+    let changes = diff::diff(&old, &new);
+    assert!(!changes.is_empty());
+    assert!(changes.iter().all(|change| change.memory == 2 && change.new.is_none()));
+}
@@ -0,0 +1,44 @@
+mod common;
+
+use boss_rc500::export::{self, Format};
+use boss_rc500::{writer, Rc500Error};
+use common::small_config;
+
+#[test]
+fn importing_an_export_restores_the_config() {
+    let original = small_config();
+    for format in [Format::Json, Format::Yaml, Format::Toml] {
+        let text = export::to_string(&export::export(&original), format).unwrap();
+
+        let mut config = small_config();
+        boss_rc500::set_setting(&mut config, 1, "TRACK2", "StpMod", 2).unwrap();
+        boss_rc500::set_name(&mut config, 0, "Changed").unwrap();
+        export::import(&mut config, &export::from_str(&text, format).unwrap()).unwrap();
+        assert!(
+            writer::render(&config) == writer::render(&original),
+            "{} did not round-trip",
+            format
+        );
+    }
+}
+
+#[test]
+fn settings_are_exported_by_display_name_and_label() {
+    let mut config = small_config();
+    boss_rc500::set_setting(&mut config, 0, "TRACK1", "StpMod", 1).unwrap();
+    let text = export::to_string(&export::export(&config), Format::Yaml).unwrap();
+    assert!(text.contains("stop: Fade Out"));
+    assert!(!text.contains("StpMod"));
+}
+
+#[test]
+fn invalid_imports_change_nothing() {
+    let original = small_config();
+    let mut config = small_config();
+    let text = "[[memories]]\nmemory = 2\nname = \"Fine\"\n\n[memories.track1]\nstop = \"Sometime\"\n";
+    let result = export::import(&mut config, &export::from_str(text, Format::Toml).unwrap());
+    let error = result.unwrap_err();
+    assert!(matches!(error, Rc500Error::InvalidValue { .. }));
+    assert!(error.message().starts_with("Memory 2: TRACK1/stop"), "{}", error);
+    assert!(writer::render(&config) == writer::render(&original));
+}
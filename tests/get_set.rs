@@ -0,0 +1,70 @@
+mod common;
+
+use boss_rc500::{Rc500Error, SettingPath};
+use common::small_config;
+
+fn path(text: &str) -> SettingPath {
+    text.parse().unwrap()
+}
+
+#[test]
+fn values_are_set_by_label_or_number() {
+    let mut config = small_config();
+    boss_rc500::set_value(&mut config, &path("2/TRACK2/StpMod"), "loop end").unwrap();
+    assert_eq!(
+        boss_rc500::get_value(&config, &path("2/TRACK2/StpMod"), false).unwrap(),
+        "Loop End"
+    );
+    assert_eq!(
+        boss_rc500::get_value(&config, &path("2/TRACK2/StpMod"), true).unwrap(),
+        "2"
+    );
+
+    boss_rc500::set_value(&mut config, &path("2/TRACK2/StpMod"), "1").unwrap();
+    assert_eq!(
+        boss_rc500::get_value(&config, &path("2/TRACK2/StpMod"), false).unwrap(),
+        "Fade Out"
+    );
+}
+
+fn is_invalid(result: Result<(), Rc500Error>) -> bool {
+    matches!(result, Err(Rc500Error::InvalidValue { .. }))
+}
+
+#[test]
+fn invalid_values_are_refused() {
+    let mut config = small_config();
+    assert!(is_invalid(boss_rc500::set_value(
+        &mut config,
+        &path("1/TRACK1/PlyLvl"),
+        "201"
+    )));
+    assert!(is_invalid(boss_rc500::set_value(
+        &mut config,
+        &path("1/TRACK1/StpMod"),
+        "Sometime"
+    )));
+    assert!(is_invalid(boss_rc500::set_value(
+        &mut config,
+        &path("1/NAME"),
+        "Much too long name"
+    )));
+    assert!(is_invalid(boss_rc500::set_value(
+        &mut config,
+        &path("3/TRACK1/PlyLvl"),
+        "100"
+    )));
+    assert_eq!(
+        boss_rc500::get_value(&config, &path("1/TRACK1/PlyLvl"), false).unwrap(),
+        "100"
+    );
+}
+
+#[test]
+fn paths_count_memories_from_one() {
+    assert_eq!(path("12/TRACK1/PlyLvl").memory, 11);
+    assert_eq!(path("12/NAME").key, None);
+    assert!("0/NAME".parse::<SettingPath>().is_err());
+    assert!("12/TRACK1".parse::<SettingPath>().is_err());
+    assert_eq!(path("12/TRACK1/PlyLvl").to_string(), "12/TRACK1/PlyLvl");
+}
@@ -0,0 +1,43 @@
+mod common;
+
+use boss_rc500::git;
+use common::small_config;
+
+#[test]
+fn textconv_has_a_line_per_setting() {
+    let text = git::textconv(&small_config());
+    assert!(text.starts_with("Memory 1 / NAME = Hello World!\nMemory 1 / TRACK1 / Reverse = Off\n"));
+    assert!(text.contains("Memory 2 / TRACK2 / Stop = Immediate\n"));
+}
+
+#[test]
+fn changes_to_different_settings_are_merged() {
+    let base = small_config();
+    let mut ours = small_config();
+    let mut theirs = small_config();
+    boss_rc500::set_setting(&mut ours, 0, "TRACK1", "StpMod", 1).unwrap();
+    boss_rc500::set_setting(&mut theirs, 1, "TRACK2", "PlyLvl", 57).unwrap();
+    boss_rc500::set_name(&mut theirs, 1, "INTRO").unwrap();
+
+    let (merged, conflicts) = git::merge(&base, &ours, &theirs);
+    assert!(conflicts.is_empty());
+    assert_eq!(boss_rc500::get_setting(&merged, 0, "TRACK1", "StpMod"), Some(1));
+    assert_eq!(boss_rc500::get_setting(&merged, 1, "TRACK2", "PlyLvl"), Some(57));
+    assert_eq!(boss_rc500::model::get_memory_name(&merged.memories[1]), "INTRO");
+}
+
+#[test]
+fn changes_to_the_same_setting_conflict() {
+    let base = small_config();
+    let mut ours = small_config();
+    let mut theirs = small_config();
+    boss_rc500::set_setting(&mut ours, 0, "TRACK1", "StpMod", 1).unwrap();
+    boss_rc500::set_setting(&mut theirs, 0, "TRACK1", "StpMod", 2).unwrap();
+    boss_rc500::set_setting(&mut theirs, 0, "TRACK1", "PlyLvl", 57).unwrap();
+
+    let (merged, conflicts) = git::merge(&base, &ours, &theirs);
+    let lines: Vec<String> = conflicts.iter().map(|c| c.to_string()).collect();
+    assert_eq!(lines, ["Memory 1 / TRACK1 / StpMod: base 0, ours 1, theirs 2"]);
+    assert_eq!(boss_rc500::get_setting(&merged, 0, "TRACK1", "StpMod"), Some(1));
+    assert_eq!(boss_rc500::get_setting(&merged, 0, "TRACK1", "PlyLvl"), Some(57));
+}
@@ -0,0 +1,62 @@
+use boss_rc500::{lint, model, slots};
+
+fn set(memory: &mut model::Memory, menu: &str, key: &str, value: usize) {
+    model::get_setting_mut(memory, menu, key).unwrap().value = value;
+}
+
+fn rules(findings: &[lint::Finding]) -> Vec<&str> {
+    findings.iter().map(|finding| finding.rule).collect()
+}
+
+#[test]
+fn fresh_memories_have_no_findings() {
+    let config = slots::default_config("MEMORY1.RC0");
+    assert!(lint::lint(&config, &lint::Allow::default()).is_empty());
+}
+
+#[test]
+fn a_pedal_used_by_ctl_and_two_assigns_is_reported_at_its_settings() {
+    let mut memory = slots::default_memory(2);
+    for (assign, target) in [("ASSIGN1", 2), ("ASSIGN4", 50)] {
+        set(&mut memory, assign, "Sw", 1);
+        set(&mut memory, assign, "Source", 0);
+        set(&mut memory, assign, "Target", target);
+    }
+    set(&mut memory, "ASSIGN4", "TargetMin", 5);
+    set(&mut memory, "MASTER", "AutoRec", 1);
+    set(&mut memory, "MASTER", "AutoRecSens", 0);
+
+    let findings = lint::lint_memory(&memory, &lint::Allow::default());
+    assert_eq!(
+        rules(&findings),
+        [
+            "assign-same-source",
+            "ctl-and-assign",
+            "ctl-and-assign",
+            "target-min-above-max",
+            "auto-rec-without-sensitivity"
+        ]
+    );
+    assert_eq!(
+        findings[0].to_string(),
+        "warning[assign-same-source] Memory 3: ASSIGN1 and ASSIGN4 are both driven by Pedal 1, \
+         with the targets T1 Clear and Tempo Down"
+    );
+    assert!(findings[1].concerns("CTL", "Pedal1"));
+    assert_eq!(findings[4].severity, lint::Severity::Error);
+}
+
+#[test]
+fn rules_are_silenced_per_memory() {
+    let mut config = slots::default_config("MEMORY1.RC0");
+    for id in [4, 7] {
+        set(&mut config.memories[id], "RHYTHM", "Level", 0);
+    }
+    let allow = lint::parse_allow("[allow]\n\"5-6\" = [\"loop-sync-without-clock\"]\n").unwrap();
+    let findings = lint::lint(&config, &allow);
+    assert_eq!(rules(&findings), ["loop-sync-without-clock", "loop-sync-without-clock"]);
+    assert!(findings.iter().all(|finding| finding.memory == 7));
+    assert_eq!(findings[0].severity, lint::Severity::Info);
+
+    assert!(lint::parse_allow("[allow]\n1 = [\"no-such-rule\"]\n").is_err());
+}
@@ -0,0 +1,339 @@
+mod common;
+
+use boss_rc500::io::{self, Device, DeviceBackend, DirectoryDevice};
+use boss_rc500::{model, slots, writer, Rc500Error, SettingPath};
+use common::TempDir;
+use std::cell::Cell;
+use std::path::{Path, PathBuf};
+
+fn data_dir(root: &Path) -> PathBuf {
+    root.join("ROLAND").join("DATA")
+}
+
+/// A fresh directory laid out like the volume of an RC500.
+fn device_root(name: &str) -> TempDir {
+    let root = TempDir::new(name);
+    std::fs::create_dir_all(data_dir(root.path())).unwrap();
+    root
+}
+
+/// Write both memory files with a full set of default memories, with the given playback level for memory 1.
+fn write_config(dir: &Path, level: &str) {
+    let mut config = common::small_config();
+    config.xml = None;
+    config.memories = (0..99).map(slots::default_memory).collect();
+    let level_path: SettingPath = "1/TRACK1/PlyLvl".parse().unwrap();
+    boss_rc500::set_value(&mut config, &level_path, level).unwrap();
+    for name in io::MEMORY_FILES {
+        writer::write(&dir.join(name).to_string_lossy(), &config).unwrap();
+    }
+}
+
+fn device_level(root: &Path) -> String {
+    let config = boss_rc500::load(&data_dir(root).to_string_lossy()).unwrap();
+    boss_rc500::get_value(&config, &"1/TRACK1/PlyLvl".parse().unwrap(), true).unwrap()
+}
+
+/// A device that truncates the first file written to it, like an interrupted copy that went unnoticed.
+struct FlakyDevice {
+    inner: DirectoryDevice,
+    failed: Cell<bool>,
+}
+
+impl DeviceBackend for FlakyDevice {
+    fn list_devices(&self) -> Result<Vec<Device>, Rc500Error> {
+        self.inner.list_devices()
+    }
+
+    fn write_file(&self, dev: &Device, name: &str, content: &[u8]) -> Result<(), Rc500Error> {
+        match self.failed.replace(true) {
+            false => self.inner.write_file(dev, name, &content[..content.len() / 2]),
+            true => self.inner.write_file(dev, name, content),
+        }
+    }
+}
+
+/// A device that truncates every write of one file.
+struct BrokenFile {
+    inner: DirectoryDevice,
+    name: &'static str,
+}
+
+impl DeviceBackend for BrokenFile {
+    fn list_devices(&self) -> Result<Vec<Device>, Rc500Error> {
+        self.inner.list_devices()
+    }
+
+    fn write_file(&self, dev: &Device, name: &str, content: &[u8]) -> Result<(), Rc500Error> {
+        match name == self.name {
+            true => self.inner.write_file(dev, name, &content[..content.len() / 2]),
+            false => self.inner.write_file(dev, name, content),
+        }
+    }
+}
+
+#[test]
+fn pull_and_push_go_through_a_directory_device() {
+    let root = device_root("push-pull");
+    write_config(&data_dir(root.path()), "10");
+    let temp = TempDir::new("push-pull-work");
+    let working_dir = temp.name();
+    let device = DirectoryDevice {
+        root: root.path().to_path_buf(),
+    };
+
+    io::pull(&device, &working_dir, false).unwrap();
+    for name in io::MEMORY_FILES {
+        assert_eq!(
+            std::fs::read(temp.join(name)).unwrap(),
+            std::fs::read(data_dir(root.path()).join(name)).unwrap()
+        );
+    }
+    assert!(io::pull(&device, &working_dir, false).is_err());
+
+    let mut config = boss_rc500::load(&working_dir).unwrap();
+    boss_rc500::set_value(&mut config, &"1/TRACK1/PlyLvl".parse().unwrap(), "30").unwrap();
+    boss_rc500::save(&mut config).unwrap();
+    io::push(&device, &working_dir).unwrap();
+    assert_eq!(device_level(root.path()), "30");
+
+    write_config(temp.path(), "20");
+    io::pull(&device, &working_dir, true).unwrap();
+    let pulled = boss_rc500::load(&working_dir).unwrap();
+    assert_eq!(
+        boss_rc500::get_value(&pulled, &"1/TRACK1/PlyLvl".parse().unwrap(), true).unwrap(),
+        "30"
+    );
+}
+
+#[test]
+fn push_archives_the_device_files() {
+    let root = device_root("push-archive");
+    write_config(&data_dir(root.path()), "10");
+    let temp = TempDir::new("push-archive-work");
+    write_config(temp.path(), "20");
+
+    let device = DirectoryDevice {
+        root: root.path().to_path_buf(),
+    };
+    io::push(&device, &temp.name()).unwrap();
+    assert_eq!(device_level(root.path()), "20");
+
+    let archives: Vec<PathBuf> = std::fs::read_dir(temp.join(".rc500").join("device-backups"))
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    assert_eq!(archives.len(), 1);
+    let archived = boss_rc500::load(&archives[0].to_string_lossy()).unwrap();
+    assert_eq!(
+        boss_rc500::get_value(&archived, &"1/TRACK1/PlyLvl".parse().unwrap(), true).unwrap(),
+        "10"
+    );
+}
+
+#[test]
+fn failed_verification_rolls_back() {
+    let root = device_root("push-rollback");
+    write_config(&data_dir(root.path()), "10");
+    let temp = TempDir::new("push-rollback-work");
+    write_config(temp.path(), "20");
+
+    let device = FlakyDevice {
+        inner: DirectoryDevice {
+            root: root.path().to_path_buf(),
+        },
+        failed: Cell::new(false),
+    };
+    let error = io::push(&device, &temp.name()).unwrap_err();
+    assert!(matches!(error, Rc500Error::Io { .. }));
+    assert!(error.to_string().contains("Put back the previous files"), "{}", error);
+    assert_eq!(device_level(root.path()), "10");
+}
+
+/// Give memory 1 audio on its second track, locally and in the WAVE folder of the device.
+fn record_first_memory(root: &Path, working_dir: &str) -> PathBuf {
+    let mut config = boss_rc500::load(working_dir).unwrap();
+    boss_rc500::set_setting(&mut config, 0, "TRACK2", "WavStat", 1).unwrap();
+    boss_rc500::save(&mut config).unwrap();
+    let waves = root.join("ROLAND").join("WAVE");
+    std::fs::create_dir_all(waves.join("001_2")).unwrap();
+    std::fs::write(waves.join("001_2").join("001_2.WAV"), b"audio").unwrap();
+    waves
+}
+
+#[test]
+fn audio_on_the_device_moves_with_a_push() {
+    let root = device_root("push-audio");
+    write_config(&data_dir(root.path()), "10");
+    let temp = TempDir::new("push-audio-work");
+    write_config(temp.path(), "20");
+    let working_dir = temp.name();
+    let waves = record_first_memory(root.path(), &working_dir);
+
+    let device = DirectoryDevice {
+        root: root.path().to_path_buf(),
+    };
+    let mut config = boss_rc500::load(&working_dir).unwrap();
+    io::push_rearranged(&device, &working_dir, &mut config, &slots::swap(0, 2)).unwrap();
+    assert_eq!(std::fs::read(waves.join("003_2").join("001_2.WAV")).unwrap(), b"audio");
+    assert!(!waves.join("001_2").exists());
+    let device_config = io::read_device_config(&device).unwrap();
+    assert_eq!(boss_rc500::get_setting(&device_config, 2, "TRACK2", "WavStat"), Some(1));
+}
+
+#[test]
+fn a_failed_push_leaves_the_audio_and_the_local_files() {
+    let root = device_root("push-audio-rollback");
+    write_config(&data_dir(root.path()), "10");
+    let temp = TempDir::new("push-audio-rollback-work");
+    write_config(temp.path(), "20");
+    let working_dir = temp.name();
+    let waves = record_first_memory(root.path(), &working_dir);
+    let local = std::fs::read(temp.join(io::MEMORY_FILES[0])).unwrap();
+
+    let device = FlakyDevice {
+        inner: DirectoryDevice {
+            root: root.path().to_path_buf(),
+        },
+        failed: Cell::new(false),
+    };
+    let mut config = boss_rc500::load(&working_dir).unwrap();
+    assert!(io::push_rearranged(&device, &working_dir, &mut config, &slots::swap(0, 2)).is_err());
+    assert!(waves.join("001_2").join("001_2.WAV").exists());
+    assert_eq!(std::fs::read_dir(&waves).unwrap().count(), 1);
+    assert_eq!(std::fs::read(temp.join(io::MEMORY_FILES[0])).unwrap(), local);
+}
+
+#[test]
+fn a_rollback_removes_the_files_that_were_new_to_the_device() {
+    let root = device_root("push-new-files");
+    write_config(&data_dir(root.path()), "10");
+    let temp = TempDir::new("push-new-files-work");
+    write_config(temp.path(), "20");
+    let fixture = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("system_corpus")
+        .join("hand_written.RC0");
+    for name in io::SYSTEM_FILES {
+        std::fs::copy(&fixture, temp.join(name)).unwrap();
+    }
+    std::fs::write(data_dir(root.path()).join("MEMORY1.TMP"), b"left over").unwrap();
+
+    let device = BrokenFile {
+        inner: DirectoryDevice {
+            root: root.path().to_path_buf(),
+        },
+        name: io::SYSTEM_FILES[1],
+    };
+    assert!(io::push(&device, &temp.name()).is_err());
+    assert_eq!(device_level(root.path()), "10");
+    let mut left: Vec<String> = std::fs::read_dir(data_dir(root.path()))
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+        .collect();
+    left.sort();
+    assert_eq!(left, io::MEMORY_FILES);
+}
+
+#[test]
+fn pushing_selected_memories_keeps_the_others() {
+    let root = device_root("push-only");
+    write_config(&data_dir(root.path()), "10");
+    let temp = TempDir::new("push-only-work");
+    write_config(temp.path(), "20");
+    let working_dir = temp.name();
+    let mut config = boss_rc500::load(&working_dir).unwrap();
+    boss_rc500::set_name(&mut config, 1, "LOCAL").unwrap();
+    boss_rc500::save(&mut config).unwrap();
+
+    let device = DirectoryDevice {
+        root: root.path().to_path_buf(),
+    };
+    let preview = io::push_only(&device, &working_dir, &[0], true).unwrap();
+    assert!(
+        preview.contains("Memory 1 (Memory01) / TRACK1 / Playback Level: 10 -> 20"),
+        "{}",
+        preview
+    );
+    assert!(!preview.contains("would reject"), "{}", preview);
+    assert_eq!(device_level(root.path()), "10");
+
+    // A memory that is not pushed but would be rejected shows up in the preview too
+    let valid = io::read_device_config(&device).unwrap();
+    let mut invalid = valid.clone();
+    model::get_setting_mut(&mut invalid.memories[2], "TRACK1", "PlyLvl")
+        .unwrap()
+        .value = 999;
+    let write_device = |config: &boss_rc500::model::Config| {
+        for name in io::MEMORY_FILES {
+            std::fs::write(data_dir(root.path()).join(name), writer::render(config)).unwrap();
+        }
+    };
+    write_device(&invalid);
+    let preview = io::push_only(&device, &working_dir, &[0], true).unwrap();
+    assert!(preview.contains("would reject the config:\n"), "{}", preview);
+    assert!(preview.ends_with("Dry run, nothing was pushed"), "{}", preview);
+    assert!(io::push_only(&device, &working_dir, &[0], false).is_err());
+    write_device(&valid);
+
+    io::push_only(&device, &working_dir, &[0], false).unwrap();
+    assert_eq!(device_level(root.path()), "20");
+    let device_config = io::read_device_config(&device).unwrap();
+    assert_eq!(
+        boss_rc500::model::get_memory_name(&device_config.memories[1]),
+        "Memory02"
+    );
+}
+
+/// Two RC500s in plain directories, like two pedals connected at once.
+struct TwoDevices {
+    roots: [PathBuf; 2],
+}
+
+impl DeviceBackend for TwoDevices {
+    fn list_devices(&self) -> Result<Vec<Device>, Rc500Error> {
+        Ok(self
+            .roots
+            .iter()
+            .map(|root| Device {
+                id: root.to_string_lossy().to_string(),
+                name: "BOSS_RC-500".to_string(),
+                path: root.to_string_lossy().to_string(),
+            })
+            .collect())
+    }
+}
+
+#[test]
+fn one_of_several_devices_is_selected_by_id_and_synced_to_another() {
+    let temps = [device_root("push-first"), device_root("push-second")];
+    let roots = [temps[0].path().to_path_buf(), temps[1].path().to_path_buf()];
+    write_config(&data_dir(&roots[0]), "10");
+    write_config(&data_dir(&roots[1]), "30");
+    let ids = roots.clone().map(|root| root.to_string_lossy().to_string());
+    let temp = TempDir::new("push-sync-work");
+    let working_dir = temp.name();
+
+    let both = TwoDevices { roots: roots.clone() };
+    let error = io::read_device_config(&both).unwrap_err();
+    assert!(matches!(error, Rc500Error::NoDevice { .. }));
+    assert!(
+        error.message().contains("--device") && error.message().contains(&ids[1]),
+        "{}",
+        error
+    );
+
+    let second = io::SelectedDevice {
+        inner: Box::new(TwoDevices { roots: roots.clone() }),
+        id: ids[1].clone(),
+    };
+    let config = io::read_device_config(&second).unwrap();
+    assert_eq!(
+        boss_rc500::get_value(&config, &"1/TRACK1/PlyLvl".parse().unwrap(), true).unwrap(),
+        "30"
+    );
+
+    io::sync(&both, &ids[0], &ids[1], &working_dir).unwrap();
+    assert_eq!(device_level(&roots[1]), "10");
+    assert!(io::sync(&both, &ids[0], "elsewhere", &working_dir).is_err());
+}
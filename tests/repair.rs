@@ -0,0 +1,85 @@
+mod common;
+
+use boss_rc500::{model, reader, repair, validate, writer};
+
+fn fresh_bank() -> Vec<u8> {
+    let dir = common::TempDir::new("repair");
+    let config = boss_rc500::init(&dir.name(), false).unwrap();
+    writer::render(&config)
+}
+
+#[test]
+fn a_good_file_needs_no_fixes() {
+    let (config, fixes) = repair::repair(&fresh_bank(), "MEMORY1.RC0");
+    assert!(fixes.is_empty(), "{:?}", fixes);
+    assert_eq!(config.memories.len(), model::NR_MEMORIES);
+}
+
+#[test]
+fn a_cut_off_file_is_salvaged_and_reported() {
+    let text = String::from_utf8(fresh_bank()).unwrap();
+    let text = text.replacen("<PlyLvl>100</PlyLvl>", "<PlyLvl>250</PlyLvl>", 1);
+    let text = text.replacen("<Rev>0</Rev>", "", 1);
+    let cut = text.find("<mem id=\"50\"").unwrap() + 200;
+    let (config, fixes) = repair::repair(&text.as_bytes()[..cut], "MEMORY1.RC0");
+
+    let messages: Vec<String> = fixes.iter().map(|fix| fix.to_string()).collect();
+    assert!(messages.contains(&"trailer: Missing, rebuilt as generation 0".to_string()));
+    assert!(messages
+        .contains(&"Memory 1/TRACK1/PlyLvl: Value 250 is outside of the range 0..=200, clamped to 200".to_string()));
+    assert!(messages.contains(&"Memory 1/TRACK1/Rev: Missing, set to the default 0".to_string()));
+    assert!(messages.contains(&"Memory 99: Missing, filled with defaults".to_string()));
+    assert_eq!(model::get_setting(&config.memories[0], "TRACK1", "PlyLvl"), Some(200));
+
+    let repaired = reader::parse(&writer::render(&config), "MEMORY1.RC0").unwrap();
+    assert!(validate::check(&repaired).is_empty());
+}
+
+#[test]
+fn readable_files_are_kept_as_they_are() {
+    let root = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    for path in [
+        common::asset("small_config.xml"),
+        root.join("tests").join("corpus").join("variations.RC0"),
+        root.join("tests").join("corpus").join("crlf.RC0"),
+    ] {
+        let bytes = std::fs::read(&path).unwrap();
+        let (config, fixes) = repair::repair(&bytes, "MEMORY1.RC0");
+        assert!(fixes.is_empty(), "{:?}: {:?}", path, fixes);
+        assert!(writer::render(&config) == bytes, "{:?} changed", path);
+    }
+
+    // The bytes after the generation counter are not understood, so unknown ones are kept too
+    let mut bytes = fresh_bank();
+    let end = bytes.len();
+    bytes[end - 1] = 0x7F;
+    let (config, fixes) = repair::repair(&bytes, "MEMORY1.RC0");
+    assert!(fixes.is_empty(), "{:?}", fixes);
+    assert!(writer::render(&config) == bytes);
+}
+
+#[test]
+fn unknown_menus_and_keys_are_kept_in_place() {
+    let text = String::from_utf8(fresh_bank()).unwrap();
+    let text = text.replacen("<PlyLvl>100</PlyLvl>", "<PlyLvl>100</PlyLvl>\n\t<Extra>5</Extra>", 1);
+    let text = text.replacen(
+        "</TRACK1>",
+        "</TRACK1>\n<MYSTERY>\n\t<A>1</A>\n\t<B>x</B>\n</MYSTERY>",
+        1,
+    );
+    let text = text.replacen("<Rev>0</Rev>", "", 1);
+    let (config, fixes) = repair::repair(text.as_bytes(), "MEMORY1.RC0");
+
+    let messages: Vec<String> = fixes.iter().map(|fix| fix.to_string()).collect();
+    assert_eq!(
+        messages,
+        [
+            "Memory 1/MYSTERY/B: Dropped the unknown key, its value 'x' is not a number",
+            "Memory 1/TRACK1/Rev: Missing, set to the default 0"
+        ]
+    );
+    let names: Vec<&str> = config.memories[0].menus.iter().map(|menu| menu.name.as_str()).collect();
+    assert_eq!(names[1..4], ["TRACK1", "MYSTERY", "TRACK2"]);
+    assert_eq!(model::get_setting(&config.memories[0], "TRACK1", "Extra"), Some(5));
+    assert_eq!(model::get_setting(&config.memories[0], "MYSTERY", "A"), Some(1));
+}
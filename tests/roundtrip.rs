@@ -0,0 +1,127 @@
+use boss_rc500::{model, reader, typed, writer};
+use std::path::PathBuf;
+
+/// Every memory file the editor is expected to reproduce byte for byte.
+fn corpus() -> Vec<PathBuf> {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let mut files = vec![root.join("assets").join("small_config.xml")];
+    let mut extra: Vec<PathBuf> = std::fs::read_dir(root.join("tests").join("corpus"))
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    extra.sort();
+    files.extend(extra);
+    files
+}
+
+fn read(path: &PathBuf) -> (Vec<u8>, model::Config) {
+    let bytes = std::fs::read(path).unwrap();
+    let config = reader::parse(&bytes, &path.to_string_lossy()).unwrap();
+    (bytes, config)
+}
+
+#[test]
+fn unmodified_files_are_written_back_unchanged() {
+    for path in corpus() {
+        let (bytes, config) = read(&path);
+        assert!(writer::render(&config) == bytes, "{:?} did not round-trip", path);
+    }
+}
+
+#[test]
+fn changing_a_value_only_changes_that_value() {
+    for path in corpus() {
+        let (bytes, mut config) = read(&path);
+        boss_rc500::set_setting(&mut config, 0, "TRACK1", "PlyLvl", 57).unwrap();
+        let expected = String::from_utf8_lossy(&bytes).replacen("<PlyLvl>100</PlyLvl>", "<PlyLvl>57</PlyLvl>", 1);
+        let rendered = writer::render(&config);
+        assert_eq!(String::from_utf8_lossy(&rendered), expected, "{:?}", path);
+    }
+}
+
+#[test]
+fn renaming_and_renaming_back_restores_the_file() {
+    for path in corpus() {
+        let (bytes, mut config) = read(&path);
+        let original = model::get_memory_name(&config.memories[1]).to_string();
+        rename(&mut config, "Renamed");
+        let renamed = reader::parse(&writer::render(&config), "renamed").unwrap();
+        assert_eq!(model::get_memory_name(&renamed.memories[1]), "Renamed");
+
+        let mut config = renamed;
+        rename(&mut config, &original);
+        assert!(writer::render(&config) == bytes, "{:?} did not round-trip", path);
+    }
+}
+
+#[test]
+fn typed_memories_convert_back_to_the_same_memories() {
+    for path in corpus() {
+        let (_, config) = read(&path);
+        for memory in &config.memories {
+            match typed::Memory::try_from(memory) {
+                Ok(typed) => assert_eq!(&model::Memory::from(&typed), memory, "{:?}", path),
+                // A key the typed memory has no field for is refused instead of lost
+                Err(e) => assert!(e.message().contains("\"Unknown\""), "{:?}: {}", path, e),
+            }
+        }
+    }
+}
+
+fn rename(config: &mut model::Config, name: &str) {
+    if let model::MenuContent::StringValueMenu(menu) = &mut config.memories[1].menus[0].content {
+        menu.value = name.to_string();
+    }
+}
+
+/// There is no dump of a real system file yet. The ones here are hand-written in the layout of the memory
+/// files, so they test the reader and the writer, but not the tag names the RC500 uses.
+#[test]
+fn unmodified_system_files_are_written_back_unchanged() {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("system_corpus");
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        let bytes = std::fs::read(&path).unwrap();
+        let mut system = reader::parse_system(&bytes, &path.to_string_lossy()).unwrap();
+        assert!(writer::render_system(&system) == bytes, "{:?} did not round-trip", path);
+
+        if let model::MenuContent::KeyValueMenu(menu) = &mut system.menus[1].content {
+            menu.settings[0].value = 15;
+        }
+        let expected = String::from_utf8_lossy(&bytes).replacen("<RxCh>0</RxCh>", "<RxCh>15</RxCh>", 1);
+        assert_eq!(String::from_utf8_lossy(&writer::render_system(&system)), expected);
+    }
+}
+
+#[test]
+fn damaged_files_give_a_parse_error_for_the_file() {
+    let error = reader::parse(b"<database></database>", "MEMORY1.RC0").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "MEMORY1.RC0: The file ends without the binary data that should follow </database>"
+    );
+    assert!(matches!(error, boss_rc500::Rc500Error::Parse { .. }));
+}
+
+#[test]
+fn parse_errors_point_at_the_line_and_setting() {
+    let xml =
+        "<database>\n<mem id=\"0\">\n<TRACK1>\n\t<PlyLvl>loud</PlyLvl>\n</TRACK1>\n</mem>\n</database>\n\x01!  \n";
+    let error = reader::parse(xml.as_bytes(), "MEMORY1.RC0").unwrap_err();
+    match &error {
+        boss_rc500::Rc500Error::Parse {
+            location: Some(location),
+            ..
+        } => {
+            assert_eq!((location.line, location.column), (4, 2));
+            assert_eq!(location.path.as_deref(), Some("1/TRACK1/PlyLvl"));
+            assert_eq!(location.snippet, "\t<PlyLvl>loud</PlyLvl>");
+        }
+        _ => panic!("Expected a located parse error, got {:?}", error),
+    }
+    assert!(error
+        .to_string()
+        .starts_with("MEMORY1.RC0:4:2: Setting value is not an int, but: loud."));
+}
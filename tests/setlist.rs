@@ -0,0 +1,45 @@
+mod common;
+
+use boss_rc500::{model, setlist, slots, Rc500Error};
+
+/// A config of four memories without audio, named A to D.
+fn four_memories() -> boss_rc500::Config {
+    let mut config = common::small_config();
+    config.memories = (0..4).map(slots::default_memory).collect();
+    for (id, name) in ["A", "B", "C", "D"].iter().enumerate() {
+        boss_rc500::set_name(&mut config, id, name).unwrap();
+    }
+    config
+}
+
+#[test]
+fn songs_end_up_in_setlist_order() {
+    let mut config = four_memories();
+    let order = setlist::order(&config, &setlist::parse("songs = [\"c\", 4]").unwrap()).unwrap();
+    assert_eq!(order, [2, 3, 0, 1]);
+    assert_eq!(
+        setlist::preview(&config, &order).lines().nth(1).unwrap(),
+        "       3 ->        1  C"
+    );
+
+    let plan = setlist::plan(&config, &order);
+    slots::apply(&mut config, &plan, None).unwrap();
+    let names: Vec<&str> = config.memories.iter().map(model::get_memory_name).collect();
+    assert_eq!(names, ["C", "D", "A", "B"]);
+}
+
+#[test]
+fn unknown_and_repeated_songs_are_refused() {
+    let config = four_memories();
+    let order = |text: &str| setlist::order(&config, &setlist::parse(text).unwrap());
+    let refused = |message: &str| Rc500Error::invalid_value(message);
+    assert_eq!(
+        order("songs = [\"E\"]").unwrap_err(),
+        refused("There is no memory named 'E'")
+    );
+    assert_eq!(order("songs = [5]").unwrap_err(), refused("There is no memory 5"));
+    assert_eq!(
+        order("songs = [\"A\", 1]").unwrap_err(),
+        refused("Memory 1 is in the setlist more than once")
+    );
+}
@@ -0,0 +1,154 @@
+mod common;
+
+use boss_rc500::{model, schema, slots, typed};
+use common::small_config;
+
+/// A config of six memories without audio, named A to F.
+fn six_memories() -> boss_rc500::Config {
+    let mut config = small_config();
+    config.memories = (0..6).map(slots::default_memory).collect();
+    for (id, name) in ["A", "B", "C", "D", "E", "F"].iter().enumerate() {
+        boss_rc500::set_name(&mut config, id, name).unwrap();
+    }
+    config
+}
+
+fn names(config: &boss_rc500::Config) -> Vec<&str> {
+    config.memories.iter().map(model::get_memory_name).collect()
+}
+
+#[test]
+fn default_memories_are_valid() {
+    let memory = slots::default_memory(6);
+    assert_eq!(model::get_memory_name(&memory), "Memory07");
+    assert!(typed::Memory::try_from(&memory).is_ok());
+    let mut config = small_config();
+    config.memories = vec![memory];
+    config.memories[0].id = 0;
+    assert!(schema::check_config(&config).is_ok());
+}
+
+/// The defaults printed in bold in docs/Boss_RC-500_Parameters.pdf.
+#[test]
+fn defaults_follow_the_parameter_guide() {
+    let config = slots::default_config("MEMORY1.RC0");
+    let guide = [
+        ("TRACK1/Rev", "Off"),
+        ("TRACK1/LoopFx", "On"),
+        ("TRACK2/PlyLvl", "100"),
+        ("TRACK1/Pan", "50"),
+        ("TRACK1/StpMod", "Immediate"),
+        ("TRACK1/LoopSync", "On"),
+        ("TRACK2/TempoSync", "On"),
+        ("MASTER/RecAction", "Record -> Play"),
+        ("MASTER/AutoRecSens", "50"),
+        ("MASTER/FadeTime", "5"),
+        ("LOOPFX/Sw", "Off"),
+        ("LOOPFX/VinylFlickFlick", "50"),
+        ("RHYTHM/Reverb", "30"),
+        ("RHYTHM/Kit", "Studio"),
+        ("RHYTHM/Beat", "2"),
+        ("RHYTHM/Stop", "Loop Stop"),
+        ("RHYTHM/Fill", "On"),
+        ("RHYTHM/Part3", "On"),
+        ("RHYTHM/Part4", "Off"),
+        ("RHYTHM/ToneLow", "10"),
+        ("CTL/Pedal1", "Current Rec/Play"),
+        ("CTL/Pedal2", "Current Stop(Clear)"),
+        ("CTL/Pedal3", "Track Select"),
+        ("CTL/Ctl1", "Loop FX"),
+        ("CTL/Ctl2", "Memory Inc"),
+        ("CTL/Exp", "Memory Level2"),
+    ];
+    for (setting, expected) in guide {
+        let path = format!("42/{}", setting).parse().unwrap();
+        assert_eq!(
+            boss_rc500::get_value(&config, &path, false).unwrap(),
+            expected,
+            "{}",
+            setting
+        );
+    }
+}
+
+#[test]
+fn moving_shifts_the_memories_in_between() {
+    let mut config = six_memories();
+    slots::apply(&mut config, &slots::move_to(1, 4), None).unwrap();
+    assert_eq!(names(&config), ["A", "C", "D", "E", "B", "F"]);
+    slots::apply(&mut config, &slots::move_to(4, 0), None).unwrap();
+    assert_eq!(names(&config), ["B", "A", "C", "D", "E", "F"]);
+    let ids: Vec<usize> = config.memories.iter().map(|memory| memory.id).collect();
+    assert_eq!(ids, [0, 1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn swapping_clearing_and_copying() {
+    let mut config = six_memories();
+    slots::apply(&mut config, &slots::swap(0, 5), None).unwrap();
+    assert_eq!(names(&config), ["F", "B", "C", "D", "E", "A"]);
+
+    boss_rc500::set_setting(&mut config, 1, "TRACK1", "StpMod", 2).unwrap();
+    slots::apply(&mut config, &slots::copy(1, slots::parse_range("3-5").unwrap()), None).unwrap();
+    assert_eq!(names(&config), ["F", "B", "C", "D", "E", "A"]);
+    for id in [2, 3, 4] {
+        assert_eq!(boss_rc500::get_setting(&config, id, "TRACK1", "StpMod"), Some(2));
+    }
+    assert_eq!(boss_rc500::get_setting(&config, 5, "TRACK1", "StpMod"), Some(0));
+
+    slots::apply(&mut config, &slots::clear(3), None).unwrap();
+    assert_eq!(model::get_memory_name(&config.memories[3]), "Memory04");
+    assert_eq!(boss_rc500::get_setting(&config, 3, "TRACK1", "StpMod"), Some(0));
+}
+
+#[test]
+fn audio_moves_along_with_its_memory() {
+    let mut config = six_memories();
+    boss_rc500::set_setting(&mut config, 0, "TRACK2", "WavStat", 1).unwrap();
+    assert!(slots::apply(&mut config.clone(), &slots::swap(0, 2), None).is_err());
+
+    let temp = common::TempDir::new("slots-waves");
+    let waves = temp.path();
+    std::fs::create_dir_all(waves.join("001_2")).unwrap();
+    std::fs::write(waves.join("001_2").join("001_2.WAV"), b"audio").unwrap();
+    std::fs::create_dir_all(waves.join("003_1")).unwrap();
+
+    slots::apply(&mut config.clone(), &slots::swap(0, 2), Some(waves)).unwrap();
+    slots::discard_audio(waves).unwrap();
+    assert!(waves.join("001_2").exists());
+    assert_eq!(std::fs::read_dir(waves).unwrap().count(), 2);
+
+    slots::apply(&mut config, &slots::swap(0, 2), Some(waves)).unwrap();
+    assert!(waves.join("001_2").exists());
+    slots::commit_audio(&slots::swap(0, 2), waves).unwrap();
+    assert_eq!(boss_rc500::get_setting(&config, 2, "TRACK2", "WavStat"), Some(1));
+    assert_eq!(std::fs::read(waves.join("003_2").join("001_2.WAV")).unwrap(), b"audio");
+    assert!(!waves.join("001_2").exists());
+    assert!(!waves.join("003_1").exists());
+}
+
+#[test]
+fn lists_of_memories_are_parsed() {
+    assert_eq!(slots::parse_list("5,7-9").unwrap(), [4, 6, 7, 8]);
+    assert_eq!(slots::parse_list("3,1-3").unwrap(), [0, 1, 2]);
+    assert!(slots::parse_list("5,").is_err());
+}
+
+#[test]
+fn resetting_a_track_menu_keeps_its_recording() {
+    let mut config = six_memories();
+    for (key, value) in [("WavStat", 1), ("WavLen", 1000), ("PlyLvl", 50)] {
+        model::get_setting_mut(&mut config.memories[2], "TRACK1", key)
+            .unwrap()
+            .value = value;
+    }
+    slots::reset_menu(&mut config, 2, "TRACK1").unwrap();
+    let memory = &config.memories[2];
+    assert_eq!(model::get_setting(memory, "TRACK1", "WavStat"), Some(1));
+    assert_eq!(model::get_setting(memory, "TRACK1", "WavLen"), Some(1000));
+    assert_eq!(model::get_setting(memory, "TRACK1", "PlyLvl"), Some(100));
+
+    slots::reset_menu(&mut config, 2, "NAME").unwrap();
+    assert_eq!(names(&config)[2], "Memory03");
+    assert!(slots::reset_menu(&mut config, 2, "NOPE").is_err());
+}
@@ -0,0 +1,32 @@
+mod common;
+
+use boss_rc500::trailer::Trailer;
+
+fn trailer(generation: u8) -> Trailer {
+    Trailer {
+        generation,
+        rest: b"!  \n".to_vec(),
+    }
+}
+
+#[test]
+fn the_higher_generation_is_newer() {
+    assert!(trailer(1).is_newer_than(&trailer(0)));
+    assert!(!trailer(0).is_newer_than(&trailer(1)));
+    assert!(!trailer(7).is_newer_than(&trailer(7)));
+    assert!(trailer(255).is_newer_than(&trailer(0)));
+    assert_eq!(trailer(255).next(), trailer(0));
+}
+
+#[test]
+fn saving_makes_the_file_the_newest() {
+    let dir = common::working_dir("trailer");
+    let mut config = boss_rc500::load_file(&dir.join("MEMORY2.RC0")).unwrap();
+    boss_rc500::set_setting(&mut config, 0, "TRACK1", "PlyLvl", 57).unwrap();
+    boss_rc500::save(&mut config).unwrap();
+
+    let loaded = boss_rc500::load(&dir.name()).unwrap();
+    assert!(loaded.filename.ends_with("MEMORY2.RC0"));
+    assert_eq!(loaded.trailer, trailer(1));
+    assert_eq!(boss_rc500::get_setting(&loaded, 0, "TRACK1", "PlyLvl"), Some(57));
+}
@@ -0,0 +1,111 @@
+mod common;
+
+use boss_rc500::{model, validate};
+use common::small_config;
+
+fn messages(config: &model::Config) -> Vec<String> {
+    validate::check(config).iter().map(|issue| issue.to_string()).collect()
+}
+
+#[test]
+fn only_the_memory_count_is_wrong_in_the_small_config() {
+    assert_eq!(messages(&small_config()), ["database: Found 2 memories, expected 99"]);
+}
+
+#[test]
+fn issues_are_located_by_memory_menu_and_key() {
+    let mut config = small_config();
+    let memory = &mut config.memories[1];
+    model::get_setting_mut(memory, "TRACK2", "PlyLvl").unwrap().value = 201;
+    if let model::MenuContent::KeyValueMenu(menu) = &mut memory.menus[3].content {
+        menu.settings.swap(0, 1);
+    }
+    memory.menus.swap(5, 6);
+    config.memories[0].id = 5;
+    config.xml = None;
+    if let model::MenuContent::StringValueMenu(menu) = &mut config.memories[0].menus[0].content {
+        menu.value = "Ünïcode".to_string();
+    }
+
+    let messages = messages(&config);
+    assert!(messages.contains(&"Memory 1: Has id 5, expected 0".to_string()));
+    assert!(
+        !messages.iter().any(|m| m.contains("expected exactly 12")),
+        "{:?}",
+        messages
+    );
+    assert!(messages.contains(&"Memory 1/NAME/C01: Character code 220 cannot be shown by the RC500".to_string()));
+    assert!(messages.contains(&"Memory 2/TRACK2/PlyLvl: Value 201 is outside of the range 0..=200".to_string()));
+    assert!(messages
+        .iter()
+        .any(|m| m.starts_with("Memory 2: The menus are not in the order NAME, TRACK1")));
+    assert!(messages
+        .iter()
+        .any(|m| m.starts_with("Memory 2/MASTER: The keys are not in the order")));
+}
+
+#[test]
+fn a_new_bank_is_valid() {
+    let temp = common::TempDir::new("validate-init");
+    let dir = temp.name();
+    let config = boss_rc500::init(&dir, false).unwrap();
+    assert_eq!(config.memories.len(), model::NR_MEMORIES);
+    assert!(validate::check_dir(&dir).is_empty());
+    assert!(boss_rc500::init(&dir, false).is_err());
+}
+
+#[test]
+fn names_are_checked_as_they_are_in_the_file() {
+    let temp = common::TempDir::new("validate-names");
+    let dir = temp.name();
+    let mut config = boss_rc500::init(&dir, false).unwrap();
+    // The writer pads the name, so the memories are checked as they will be written
+    boss_rc500::set_name(&mut config, 0, "SHORT").unwrap();
+    assert!(validate::check(&config).is_empty());
+    config.xml = None;
+    assert!(validate::check(&config).is_empty());
+
+    for name in ["MEMORY1.RC0", "MEMORY2.RC0"] {
+        let text = String::from_utf8(std::fs::read(temp.join(name)).unwrap()).unwrap();
+        let text = text.replacen("<C12>32</C12>", "", 1);
+        std::fs::write(temp.join(name), text).unwrap();
+    }
+    let messages: Vec<String> = validate::check_dir(&dir)
+        .iter()
+        .map(|issue| issue.to_string())
+        .collect();
+    assert_eq!(
+        messages,
+        [
+            "MEMORY1.RC0/Memory 1/NAME: Has 11 characters, expected exactly 12",
+            "MEMORY2.RC0/Memory 1/NAME: Has 11 characters, expected exactly 12"
+        ]
+    );
+}
+
+#[test]
+fn system_files_are_checked_along_with_the_memories() {
+    let temp = common::TempDir::new("validate-system");
+    let dir = temp.name();
+    boss_rc500::init(&dir, false).unwrap();
+    let fixture = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("system_corpus")
+        .join("hand_written.RC0");
+    let text = String::from_utf8(std::fs::read(fixture).unwrap()).unwrap();
+    std::fs::write(temp.join("SYSTEM1.RC0"), &text).unwrap();
+    assert!(validate::check_dir(&dir).is_empty());
+
+    // The keys of the system settings are not known, so their values are not checked.
+    let text = text.replace("<Contrast>5</Contrast>", "<Contrast>99</Contrast>");
+    std::fs::write(temp.join("SYSTEM1.RC0"), text).unwrap();
+    assert!(validate::check_dir(&dir).is_empty());
+
+    std::fs::write(temp.join("SYSTEM1.RC0"), "<database>").unwrap();
+    let messages: Vec<String> = validate::check_dir(&dir)
+        .iter()
+        .map(|issue| issue.to_string())
+        .collect();
+    assert_eq!(messages.len(), 1);
+    assert!(messages[0].starts_with("SYSTEM1.RC0/"), "{:?}", messages);
+}